@@ -0,0 +1,222 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::runner::{Part, Solved, Solver};
+use crate::{Answer, Result};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "./etc/answers.txt";
+
+const VERSION: u32 = 1;
+const HEADER: &str = "# aoc-2015 answers v";
+
+/// A checksum identifying a puzzle input. Trailing whitespace is ignored,
+/// as it is when solving.
+pub fn checksum(input: &str) -> String {
+    format!("{:x}", md5::compute(input.trim_end()))
+}
+
+/// The known correct answers, keyed by day, part and input checksum.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, Part, String), String>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part, checksum: &str) -> Option<&str> {
+        self.0.get(&(day, part, checksum.to_string())).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, checksum: &str, answer: &Answer) {
+        self.0.insert((day, part, checksum.to_string()), answer.to_string());
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("could not read {}: {}", path.display(), e))
+        })?;
+
+        Answers::parse(&text).map_err(|(line, reason)| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}, line {}: {}", path.display(), line, reason)
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    fn parse(text: &str) -> std::result::Result<Answers, (usize, String)> {
+        let mut answers = BTreeMap::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if let Some(version) = line.strip_prefix(HEADER) {
+                if version.parse() != Ok(VERSION) {
+                    return Err((i + 1, format!("unsupported version '{}'", version)));
+                }
+                continue;
+            }
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // The answer is the rest of the line, so it may contain spaces.
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            let entry = match fields[..] {
+                [day, part, sum, answer] => day.parse().ok()
+                    .zip(part.parse().ok().and_then(Part::from_number))
+                    .map(|(d, p)| ((d, p, sum.to_string()), answer.trim().to_string())),
+                _ => None,
+            };
+
+            match entry {
+                Some((key, answer)) => answers.insert(key, answer),
+                None => return Err((i + 1, "invalid answer entry".to_string())),
+            };
+        }
+
+        Ok(Answers(answers))
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}{}", HEADER, VERSION)?;
+        writeln!(f, "# day part input-md5 answer")?;
+
+        for ((day, part, sum), answer) in &self.0 {
+            writeln!(f, "{} {} {} {}", day, part, sum, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Mismatch { expected: String },
+    /// There is no known answer for this input.
+    Missing,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub checksum: String,
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {}, Part {}: ", self.day, self.part)?;
+
+        match &self.outcome {
+            Outcome::Correct => write!(f, "ok ({})", self.answer),
+            Outcome::Mismatch { expected } => {
+                write!(f, "MISMATCH: expected {}, got {}", expected, self.answer)
+            },
+            Outcome::Missing => write!(f, "missing, got {}", self.answer),
+        }
+    }
+}
+
+/// Solve the given parts and compare the answers against the known ones.
+pub fn verify(answers: &Answers, solver: &dyn Solver, input: &str, parts: &[Part])
+    -> Result<Vec<Check>> {
+
+    let sum = checksum(input);
+
+    Ok(solver.solve(input, parts)?.into_iter().map(|Solved { part, answer, .. }| {
+        let outcome = match answers.get(solver.day(), part, &sum) {
+            Some(expected) if expected == answer.to_string() => Outcome::Correct,
+            Some(expected) => Outcome::Mismatch { expected: expected.to_string() },
+            None => Outcome::Missing,
+        };
+
+        Check { day: solver.day(), part, checksum: sum.clone(), answer, outcome }
+    }).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(checksum("abc"), checksum("abc\n"));
+        assert_ne!(checksum("abc"), checksum("abd"));
+        assert_eq!(32, checksum("").len());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, "abc", &Answer::Signed(-3));
+        answers.insert(5, Part::Two, "def", &Answer::Text("two words".to_string()));
+
+        let text = answers.to_string();
+        assert!(text.starts_with("# aoc-2015 answers v1\n"));
+        assert_eq!(Ok(answers.clone()), Answers::parse(&text));
+        assert_eq!(Some("two words"), answers.get(5, Part::Two, "def"));
+        assert_eq!(None, answers.get(5, Part::One, "def"));
+    }
+
+    #[test]
+    fn bad_files() {
+        assert_eq!(1, Answers::parse("# aoc-2015 answers v2").unwrap_err().0);
+        assert_eq!(2, Answers::parse("1 1 abc 3\n1 3 abc 4").unwrap_err().0);
+        assert_eq!(1, Answers::parse("1 1 abc").unwrap_err().0);
+    }
+
+    #[test]
+    fn verify_answers() {
+        let solver = runner::solver(1).unwrap();
+        let input = "(()))(";
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, &checksum(input), &Answer::Signed(0));
+        answers.insert(1, Part::Two, &checksum(input), &Answer::Signed(4));
+
+        let checks = verify(&answers, solver, input, Part::BOTH).unwrap();
+        assert_eq!(Outcome::Correct, checks[0].outcome);
+        assert_eq!(Outcome::Mismatch { expected: "4".to_string() }, checks[1].outcome);
+        assert_eq!("Day 1, Part 2: MISMATCH: expected 4, got 5", checks[1].to_string());
+
+        let checks = verify(&answers, solver, "(", &[Part::One]).unwrap();
+        assert_eq!(Outcome::Missing, checks[0].outcome);
+    }
+
+    #[test]
+    fn recorded_answers() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_ANSWERS_FILE);
+        let answers = Answers::load(&path).unwrap();
+        let input = crate::input::Source::Dir(path.parent().unwrap().to_path_buf())
+            .load("not_quite_lisp").unwrap();
+
+        assert_eq!(14, answers.len());
+        assert_eq!(Some("138"), answers.get(1, Part::One, &checksum(&input)));
+    }
+}
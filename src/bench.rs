@@ -0,0 +1,356 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+use crate::runner::{Part, Solver};
+use crate::Result;
+
+const BASELINE_HEADER: &str = "# aoc-2015 benchmark baseline v1";
+
+/// A stage of solving a puzzle that can be timed on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve(Part::One) => "part1",
+            Phase::Solve(Part::Two) => "part2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Phase> {
+        match key {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Solve(Part::One)),
+            "part2" => Some(Phase::Solve(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    /// The size of the input, used to work out throughput.
+    pub bytes: usize,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>, bytes: usize) -> Stats {
+        assert!(!samples.is_empty(), "Cannot summarise zero samples.");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        Stats {
+            runs: n,
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+            bytes,
+        }
+    }
+
+    /// Input bytes processed per second, at the median time.
+    pub fn throughput(&self) -> f64 {
+        let secs = self.median.as_secs_f64();
+
+        if secs == 0.0 {
+            f64::INFINITY
+        } else {
+            self.bytes as f64 / secs
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Time parsing, then each of `parts`, `runs` times over.
+pub fn benchmark(solver: &dyn Solver, input: &str, parts: &[Part], runs: usize)
+    -> Result<Vec<Measurement>> {
+
+    let samples = solver.time(input, parts, runs.max(1))?;
+
+    Ok(samples.into_iter().map(|(phase, s)| Measurement {
+        day: solver.day(),
+        phase,
+        stats: Stats::from_samples(s, input.len()),
+    }).collect())
+}
+
+pub fn report(measurements: &[Measurement]) -> String {
+    let mut out = format!(
+        "{:>3}  {:<6}  {:>5}  {:>10}  {:>10}  {:>10}  {:>12}\n",
+        "Day", "Phase", "Runs", "Min", "Median", "Max", "Throughput"
+    );
+
+    for m in measurements {
+        out.push_str(&format!(
+            "{:>3}  {:<6}  {:>5}  {:>10}  {:>10}  {:>10}  {:>12}\n",
+            m.day, m.phase, m.stats.runs, format_duration(m.stats.min),
+            format_duration(m.stats.median), format_duration(m.stats.max),
+            format_throughput(m.stats.throughput())
+        ));
+    }
+
+    out
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+fn format_throughput(bytes_per_sec: f64) -> String {
+    if !bytes_per_sec.is_finite() {
+        "-".to_string()
+    } else if bytes_per_sec < 1e6 {
+        format!("{:.2} KB/s", bytes_per_sec / 1e3)
+    } else if bytes_per_sec < 1e9 {
+        format!("{:.2} MB/s", bytes_per_sec / 1e6)
+    } else {
+        format!("{:.2} GB/s", bytes_per_sec / 1e9)
+    }
+}
+
+/// Median times from an earlier run, keyed by day and phase.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Baseline(HashMap<(u8, Phase), Duration>);
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Baseline {
+        Baseline(measurements.iter().map(|m| ((m.day, m.phase), m.stats.median)).collect())
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.0.get(&(day, phase)).copied()
+    }
+
+    /// Merge newer timings into this baseline, replacing any older ones.
+    pub fn update(&mut self, other: Baseline) {
+        self.0.extend(other.0);
+    }
+
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("could not read {}: {}", path.display(), e))
+        })?;
+
+        Baseline::parse(&text).map_err(|line| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}, line {}: invalid baseline entry", path.display(), line)
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    // Returns the number of the first bad line on failure.
+    fn parse(text: &str) -> std::result::Result<Baseline, usize> {
+        let mut entries = HashMap::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let entry = match fields[..] {
+                [day, phase, nanos] => day.parse().ok()
+                    .zip(Phase::from_key(phase))
+                    .zip(nanos.parse().ok().map(Duration::from_nanos)),
+                _ => None,
+            };
+
+            match entry {
+                Some((key, median)) => entries.insert(key, median),
+                None => return Err(i + 1),
+            };
+        }
+
+        Ok(Baseline(entries))
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys: Vec<&(u8, Phase)> = self.0.keys().collect();
+        keys.sort_unstable();
+
+        writeln!(f, "{}", BASELINE_HEADER)?;
+        for key in keys {
+            writeln!(f, "{} {} {}", key.0, key.1.key(), self.0[key].as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// How much slower the current run is, as a percentage of the baseline.
+    pub fn slowdown(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "day {}, {}: {} -> {} (+{:.1}%)", self.day, self.phase,
+            format_duration(self.baseline), format_duration(self.current), self.slowdown()
+        )
+    }
+}
+
+/// Find every measurement whose median is more than `threshold` percent
+/// slower than the baseline. Phases missing from the baseline are skipped.
+pub fn regressions(baseline: &Baseline, measurements: &[Measurement], threshold: f64)
+    -> Vec<Regression> {
+
+    measurements.iter().filter_map(|m| {
+        let base = baseline.get(m.day, m.phase)?;
+        let limit = base.as_secs_f64() * (1.0 + threshold / 100.0);
+
+        if m.stats.median.as_secs_f64() > limit {
+            Some(Regression { day: m.day, phase: m.phase, baseline: base, current: m.stats.median })
+        } else {
+            None
+        }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn measurement(day: u8, phase: Phase, median: Duration) -> Measurement {
+        Measurement { day, phase, stats: Stats::from_samples(vec![median], 100) }
+    }
+
+    #[test]
+    fn stats() {
+        let s = Stats::from_samples(vec![ms(5), ms(1), ms(3)], 1_000);
+        assert_eq!((3, ms(1), ms(3), ms(5)), (s.runs, s.min, s.median, s.max));
+        assert_eq!(1_000.0 / 0.003, s.throughput());
+
+        let s = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)], 0);
+        assert_eq!(ms(3), s.median);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.50µs", format_duration(Duration::from_nanos(1_500)));
+        assert_eq!("2.00ms", format_duration(ms(2)));
+        assert_eq!("3.25s", format_duration(ms(3_250)));
+    }
+
+    #[test]
+    fn bench_a_day() {
+        let solver = runner::solver(1).unwrap();
+        let results = benchmark(solver, "(()))", Part::BOTH, 3).unwrap();
+
+        let phases: Vec<Phase> = results.iter().map(|m| m.phase).collect();
+        assert_eq!(vec![Phase::Parse, Phase::Solve(Part::One), Phase::Solve(Part::Two)], phases);
+        assert!(results.iter().all(|m| m.day == 1 && m.stats.runs == 3 && m.stats.bytes == 5));
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let baseline = Baseline::from_measurements(&[
+            measurement(1, Phase::Parse, Duration::from_nanos(120)),
+            measurement(6, Phase::Solve(Part::Two), ms(40)),
+        ]);
+
+        let text = baseline.to_string();
+        assert!(text.starts_with(BASELINE_HEADER));
+        assert!(text.contains("6 part2 40000000\n"));
+        assert_eq!(Ok(baseline), Baseline::parse(&text));
+
+        assert_eq!(Err(2), Baseline::parse("1 parse 10\n1 part3 10"));
+        assert_eq!(Err(1), Baseline::parse("1 parse"));
+    }
+
+    #[test]
+    fn find_regressions() {
+        let baseline = Baseline::from_measurements(&[
+            measurement(1, Phase::Parse, ms(10)),
+            measurement(1, Phase::Solve(Part::One), ms(10)),
+        ]);
+
+        let current = [
+            measurement(1, Phase::Parse, ms(11)),
+            measurement(1, Phase::Solve(Part::One), ms(15)),
+            measurement(1, Phase::Solve(Part::Two), ms(100)),
+        ];
+
+        let found = regressions(&baseline, &current, 20.0);
+        assert_eq!(1, found.len());
+        assert_eq!(Phase::Solve(Part::One), found[0].phase);
+        assert!((found[0].slowdown() - 50.0).abs() < 1e-9);
+        assert_eq!(2, regressions(&baseline, &current, 5.0).len());
+    }
+}
@@ -0,0 +1,695 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use aoc_2015::answers::{self, Answers, Outcome};
+use aoc_2015::bench::{self, Baseline};
+use aoc_2015::day01::{self, FloorStats};
+use aoc_2015::day02::{self, report::Report};
+use aoc_2015::generate;
+use aoc_2015::input::Source;
+use aoc_2015::output::{Format, Record};
+use aoc_2015::pool;
+use aoc_2015::runner::{self, Part, Solver, SOLVERS};
+use aoc_2015::Error;
+
+const USAGE: &str = "\
+Usage: aoc [COMMAND] [DAYS] [OPTIONS]
+
+Commands:
+    run     Run the solvers for the selected days (the default)
+    bench   Time parsing and solving for the selected days
+    verify  Check answers for the selected days against the known answers
+    generate
+            Print a random, valid input for the selected day
+    floors  Show how often day 1's elevator visits each floor, and how
+            long it stays above and below ground
+    parcels Break down day 2's order parcel by parcel, with statistics
+    list    List the available days
+
+Days:
+    all     Every available day (the default)
+    N       A single day, e.g. 6
+    N-M     A range of days, e.g. 1-4
+    N,M     A list of days or ranges, e.g. 1,3,5-7
+
+Options:
+    -p, --part <1|2>        Only run the given part
+    -f, --format <FORMAT>   Print answers as text (the default), json
+                            (one object per line) or csv; floors prints
+                            text or json only
+    -d, --input-dir <DIR>   Read each day's input from <DIR>/<name>.txt
+    -i, --input <FILE>      Read the input from <FILE> (\"-\" for stdin)
+        --inline <TEXT>     Use <TEXT> as the input
+    -j, --jobs <N>          Solve up to <N> days and parts at once (default
+                            one per CPU); benchmarks always run one at a time
+    -h, --help              Print this message
+
+Benchmark options:
+    -r, --runs <N>          Time each phase <N> times (default 10)
+        --save <FILE>       Save the median times to <FILE> as a baseline
+        --baseline <FILE>   Report phases more than --threshold slower
+                            than in <FILE>, and exit with an error
+        --threshold <PCT>   Allowed slowdown in percent (default 10)
+
+Verify options:
+    -a, --answers <FILE>    Read the known answers from <FILE>
+                            (default ./etc/answers.txt)
+        --record            Add any answers that are not yet known to <FILE>
+
+Generate options:
+        --seed <N>          Seed the random generator with <N> (default 2015)
+        --size <N>          The size of input to generate: characters for
+                            days 1 and 3, key length for day 4, wires for
+                            day 7 and lines otherwise
+
+Inputs are read from $AOC_INPUT_DIR, or ./etc, by default. --input and
+--inline need a single day to be selected.";
+
+const EXIT_FAILURE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+const DEFAULT_SEED: u64 = 2015;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run,
+    Bench,
+    Verify,
+    Generate,
+    Floors,
+    Parcels,
+    List,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    command: Command,
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    source: Source,
+    format: Format,
+    jobs: usize,
+    runs: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    answers: PathBuf,
+    record: bool,
+    seed: u64,
+    size: Option<usize>,
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, USAGE);
+            process::exit(EXIT_USAGE);
+        },
+    };
+
+    match options.command {
+        Command::Help => println!("{}", USAGE),
+        Command::List => {
+            for solver in SOLVERS {
+                println!("Day {}: {}", solver.day(), solver.name());
+            }
+        },
+        Command::Run => process::exit(run(&options)),
+        Command::Bench => process::exit(benchmark(&options)),
+        Command::Verify => process::exit(verify(&options)),
+        Command::Floors => process::exit(floors(&options)),
+        Command::Parcels => process::exit(parcels(&options)),
+        Command::Generate => {
+            let day = options.days[0];
+            let size = options.size.unwrap_or_else(|| generate::default_size(day));
+            let input = generate::generate(day, options.seed, size)
+                .expect("Every registered day has a generator.");
+
+            println!("{}", input);
+        },
+    }
+}
+
+/// Load the input for each selected day and call `f` on each of its parts,
+/// spreading the work over `--jobs` threads. The results are grouped by
+/// day, in day order, with the first error for a day replacing its results.
+fn solve_days<R, F>(options: &Options, f: F) -> Vec<(u8, Result<Vec<R>, Error>)>
+where
+    R: Send,
+    F: Fn(&dyn Solver, &str, Part) -> Result<Vec<R>, Error> + Sync,
+{
+    let loaded: Vec<(&dyn Solver, Result<String, Error>)> = options.days.iter().map(|&day| {
+        let solver = runner::solver(day).expect("Days are checked when parsed.");
+        (solver, options.source.load(solver.name()).map_err(Error::from))
+    }).collect();
+
+    let tasks: Vec<(usize, Part)> = loaded.iter().enumerate()
+        .filter(|(_, (_, input))| input.is_ok())
+        .flat_map(|(n, _)| options.parts.iter().map(move |&part| (n, part)))
+        .collect();
+
+    let solved = pool::parallel_map(&tasks, options.jobs, |&(n, part)| {
+        let (solver, input) = &loaded[n];
+        f(*solver, input.as_ref().expect("Only loaded inputs are solved."), part)
+    });
+
+    let mut days: Vec<(u8, Result<Vec<R>, Error>)> = loaded.into_iter()
+        .map(|(solver, input)| (solver.day(), input.map(|_| Vec::new())))
+        .collect();
+
+    for (&(n, _), result) in tasks.iter().zip(solved) {
+        let entry = &mut days[n].1;
+
+        match (entry.as_mut(), result) {
+            (Ok(all), Ok(r)) => all.extend(r),
+            (Ok(_), Err(e)) => *entry = Err(e),
+            _ => (),
+        }
+    }
+
+    days
+}
+
+fn run(options: &Options) -> i32 {
+    let mut status = 0;
+
+    let results = solve_days(options, |solver, input, part| {
+        let input_hash = answers::checksum(input);
+
+        Ok(solver.solve(input, &[part])?.into_iter().map(|s| {
+            options.format.record(&Record {
+                day: solver.day(),
+                part: s.part,
+                answer: &s.answer,
+                input_hash: &input_hash,
+                elapsed: s.elapsed,
+            })
+        }).collect())
+    });
+
+    if let Some(header) = options.format.header() {
+        println!("{}", header);
+    }
+
+    for (day, result) in results {
+        match result {
+            Ok(records) => {
+                for record in records {
+                    println!("{}", record);
+                }
+            },
+            Err(e) => {
+                report_error(day, &e);
+                status = EXIT_FAILURE;
+            },
+        }
+    }
+
+    status
+}
+
+fn benchmark(options: &Options) -> i32 {
+    let mut status = 0;
+    let mut measurements = Vec::new();
+
+    for &day in &options.days {
+        let solver = runner::solver(day).expect("Days are checked when parsed.");
+        let timed = options.source.load(solver.name()).map_err(|e| e.into())
+            .and_then(|input| bench::benchmark(solver, &input, &options.parts, options.runs));
+
+        match timed {
+            Ok(m) => measurements.extend(m),
+            Err(e) => {
+                report_error(day, &e);
+                status = EXIT_FAILURE;
+            },
+        }
+    }
+
+    print!("{}", bench::report(&measurements));
+
+    if let Some(path) = &options.baseline {
+        match Baseline::load(path) {
+            Ok(baseline) => {
+                let slower = bench::regressions(&baseline, &measurements, options.threshold);
+
+                for regression in &slower {
+                    eprintln!("Regression: {}", regression);
+                }
+
+                if !slower.is_empty() {
+                    status = EXIT_FAILURE;
+                }
+            },
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                status = EXIT_FAILURE;
+            },
+        }
+    }
+
+    if let Some(path) = &options.save {
+        // Keep the timings for any days that were not run this time.
+        let mut baseline = Baseline::load(path).unwrap_or_default();
+        baseline.update(Baseline::from_measurements(&measurements));
+
+        if let Err(e) = baseline.save(path) {
+            eprintln!("Error: could not save {}: {}", path.display(), e);
+            status = EXIT_FAILURE;
+        }
+    }
+
+    status
+}
+
+fn verify(options: &Options) -> i32 {
+    let mut known = match Answers::load(&options.answers) {
+        Ok(a) => a,
+        Err(e) if options.record && e.kind() == std::io::ErrorKind::NotFound => Answers::default(),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return EXIT_FAILURE;
+        },
+    };
+
+    let mut status = 0;
+    let mut checks = Vec::new();
+
+    let results = solve_days(options, |solver, input, part| {
+        answers::verify(&known, solver, input, &[part])
+    });
+
+    for (day, checked) in results {
+        match checked {
+            Ok(c) => checks.extend(c),
+            Err(e) => {
+                report_error(day, &e);
+                status = EXIT_FAILURE;
+            },
+        }
+    }
+
+    let mut counts = [0; 3];
+    for check in &checks {
+        println!("{}", check);
+
+        match check.outcome {
+            Outcome::Correct => counts[0] += 1,
+            Outcome::Mismatch { .. } => counts[1] += 1,
+            Outcome::Missing => counts[2] += 1,
+        }
+    }
+
+    println!(
+        "{} checked: {} ok, {} mismatched, {} missing",
+        checks.len(), counts[0], counts[1], counts[2]
+    );
+
+    if counts[1] > 0 {
+        status = EXIT_FAILURE;
+    }
+
+    if options.record && counts[2] > 0 {
+        for check in checks.iter().filter(|c| c.outcome == Outcome::Missing) {
+            known.insert(check.day, check.part, &check.checksum, &check.answer);
+        }
+
+        match known.save(&options.answers) {
+            Ok(()) => println!("Recorded {} new answers in {}", counts[2], options.answers.display()),
+            Err(e) => {
+                eprintln!("Error: could not save {}: {}", options.answers.display(), e);
+                status = EXIT_FAILURE;
+            },
+        }
+    }
+
+    status
+}
+
+fn floors(options: &Options) -> i32 {
+    let input = match load(options, 1) {
+        Some(i) => i,
+        None => return EXIT_FAILURE,
+    };
+
+    if let Err(e) = day01::check_instructions(input.trim_end(), false) {
+        report_error(1, &e);
+        return EXIT_FAILURE;
+    }
+
+    let stats = FloorStats::new(&input);
+
+    match options.format {
+        Format::Json => println!("{}", stats.json()),
+        _ => print!("{}", stats.table()),
+    }
+
+    0
+}
+
+fn parcels(options: &Options) -> i32 {
+    let input = match load(options, 2) {
+        Some(i) => i,
+        None => return EXIT_FAILURE,
+    };
+
+    match day02::parse_input(input.trim_end()) {
+        Ok(parcels) => {
+            print!("{}", Report::new(&parcels).format(options.format));
+            0
+        },
+        Err(e) => {
+            report_error(2, &e);
+            EXIT_FAILURE
+        },
+    }
+}
+
+/// Load the input for one day, reporting any error.
+fn load(options: &Options, day: u8) -> Option<String> {
+    let name = runner::solver(day).expect("Days are checked when parsed.").name();
+
+    options.source.load(name).map_err(|e| report_error(day, &e)).ok()
+}
+
+fn report_error(day: u8, e: &dyn std::fmt::Display) {
+    let e = e.to_string();
+
+    // Parse errors come one per line, and already name the day.
+    for line in e.lines() {
+        if line.starts_with("day ") {
+            eprintln!("Error: {}", line);
+        } else {
+            eprintln!("Error: day {}: {}", day, line);
+        }
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let mut command = None;
+    let mut days = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut source = Source::Default;
+    let mut format = Format::Text;
+    let mut jobs = pool::default_jobs();
+    let mut runs = DEFAULT_RUNS;
+    let mut save = None;
+    let mut baseline = None;
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut answers = PathBuf::from(answers::DEFAULT_ANSWERS_FILE);
+    let mut record = false;
+    let mut seed = DEFAULT_SEED;
+    let mut size = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => command = Some(Command::Help),
+            "-p" | "--part" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                parts = vec![parse_part(&part)?];
+            },
+            "-f" | "--format" => {
+                format = args.next().ok_or("--format needs a value")?.parse()?;
+            },
+            "-d" | "--input-dir" => {
+                let dir = args.next().ok_or("--input-dir needs a value")?;
+                source = Source::Dir(PathBuf::from(dir));
+            },
+            "-i" | "--input" => {
+                source = match args.next().ok_or("--input needs a value")?.as_str() {
+                    "-" => Source::Stdin,
+                    file => Source::File(PathBuf::from(file)),
+                };
+            },
+            "--inline" => {
+                let text = args.next().ok_or("--inline needs a value")?;
+                source = Source::Inline(text);
+            },
+            "-j" | "--jobs" => {
+                let n = args.next().ok_or("--jobs needs a value")?;
+                jobs = match n.parse() {
+                    Ok(j) if j > 0 => j,
+                    _ => return Err(format!("invalid number of jobs '{}'", n)),
+                };
+            },
+            "-r" | "--runs" => {
+                let n = args.next().ok_or("--runs needs a value")?;
+                runs = match n.parse() {
+                    Ok(r) if r > 0 => r,
+                    _ => return Err(format!("invalid number of runs '{}'", n)),
+                };
+            },
+            "--save" => save = Some(PathBuf::from(args.next().ok_or("--save needs a value")?)),
+            "--baseline" => {
+                let file = args.next().ok_or("--baseline needs a value")?;
+                baseline = Some(PathBuf::from(file));
+            },
+            "--threshold" => {
+                let pct = args.next().ok_or("--threshold needs a value")?;
+                threshold = match pct.parse() {
+                    Ok(t) if t >= 0.0 => t,
+                    _ => return Err(format!("invalid threshold '{}'", pct)),
+                };
+            },
+            "-a" | "--answers" => {
+                answers = PathBuf::from(args.next().ok_or("--answers needs a value")?);
+            },
+            "--record" => record = true,
+            "--seed" => {
+                let n = args.next().ok_or("--seed needs a value")?;
+                seed = n.parse().map_err(|_| format!("invalid seed '{}'", n))?;
+            },
+            "--size" => {
+                let n = args.next().ok_or("--size needs a value")?;
+                size = Some(n.parse().map_err(|_| format!("invalid size '{}'", n))?);
+            },
+            "run" if command.is_none() && days.is_none() => command = Some(Command::Run),
+            "bench" if command.is_none() && days.is_none() => command = Some(Command::Bench),
+            "verify" if command.is_none() && days.is_none() => command = Some(Command::Verify),
+            "generate" if command.is_none() && days.is_none() => {
+                command = Some(Command::Generate)
+            },
+            "floors" if command.is_none() && days.is_none() => command = Some(Command::Floors),
+            "parcels" if command.is_none() && days.is_none() => {
+                command = Some(Command::Parcels)
+            },
+            "list" if command.is_none() && days.is_none() => command = Some(Command::List),
+            a if a.starts_with('-') => return Err(format!("unknown option '{}'", a)),
+            a if days.is_none() => days = Some(parse_days(a)?),
+            a => return Err(format!("unexpected argument '{}'", a)),
+        }
+    }
+
+    if command == Some(Command::Floors) {
+        if days.is_some() {
+            return Err("floors always uses day 1".to_string());
+        }
+
+        if format == Format::Csv {
+            return Err("floors prints text or json only".to_string());
+        }
+
+        days = Some(vec![1]);
+    }
+
+    if command == Some(Command::Parcels) {
+        if days.is_some() {
+            return Err("parcels always uses day 2".to_string());
+        }
+
+        days = Some(vec![2]);
+    }
+
+    let days = match days {
+        Some(d) => d,
+        None => SOLVERS.iter().map(|s| s.day()).collect(),
+    };
+
+    if !source.is_per_puzzle() && days.len() != 1 {
+        return Err("--input and --inline need a single day".to_string());
+    }
+
+    if command == Some(Command::Generate) && days.len() != 1 {
+        return Err("generate needs a single day".to_string());
+    }
+
+    Ok(Options {
+        command: command.unwrap_or(Command::Run),
+        days,
+        parts,
+        source,
+        format,
+        jobs,
+        runs,
+        save,
+        baseline,
+        threshold,
+        answers,
+        record,
+        seed,
+        size,
+    })
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    part.parse().ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("invalid part '{}'", part))
+}
+
+fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    if spec == "all" {
+        return Ok(SOLVERS.iter().map(|s| s.day()).collect());
+    }
+
+    let mut days = Vec::new();
+
+    for item in spec.split(',') {
+        let (first, last) = match item.find('-') {
+            Some(i) => (parse_day(&item[..i])?, parse_day(&item[i + 1..])?),
+            None => {
+                let d = parse_day(item)?;
+                (d, d)
+            },
+        };
+
+        if first > last {
+            return Err(format!("invalid range '{}'", item));
+        }
+
+        for day in first..=last {
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+    }
+
+    days.sort_unstable();
+    Ok(days)
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.trim().parse() {
+        Ok(d) if runner::solver(d).is_some() => Ok(d),
+        Ok(d) => Err(format!("no solver for day {}", d)),
+        Err(_) => Err(format!("invalid day '{}'", day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(a: &[&str]) -> impl Iterator<Item = String> {
+        a.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(Ok(vec![3]), parse_days("3"));
+        assert_eq!(Ok(vec![2, 3, 4]), parse_days("2-4"));
+        assert_eq!(Ok(vec![1, 5, 6, 7]), parse_days("7,1,5-6"));
+        assert_eq!(Ok(vec![1, 2, 3, 4, 5, 6, 7]), parse_days("all"));
+        assert!(parse_days("4-2").is_err());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn defaults() {
+        let options = parse_args(args(&[])).unwrap();
+        assert_eq!(Command::Run, options.command);
+        assert_eq!(7, options.days.len());
+        assert_eq!(Part::BOTH, &options.parts[..]);
+        assert_eq!(Source::Default, options.source);
+    }
+
+    #[test]
+    fn commands_and_options() {
+        let options = parse_args(args(&["run", "3", "--part", "2"])).unwrap();
+        assert_eq!(Command::Run, options.command);
+        assert_eq!(vec![3], options.days);
+        assert_eq!(vec![Part::Two], options.parts);
+
+        let options = parse_args(args(&["1-2", "-p", "1"])).unwrap();
+        assert_eq!(vec![1, 2], options.days);
+        assert_eq!(vec![Part::One], options.parts);
+
+        let options = parse_args(args(&["4", "--inline", "abcdef"])).unwrap();
+        assert_eq!(Source::Inline("abcdef".to_string()), options.source);
+
+        let options = parse_args(args(&["-i", "-", "3"])).unwrap();
+        assert_eq!(Source::Stdin, options.source);
+
+        let options = parse_args(args(&["-f", "csv"])).unwrap();
+        assert_eq!(Format::Csv, options.format);
+
+        let options = parse_args(args(&["--jobs", "3"])).unwrap();
+        assert_eq!(3, options.jobs);
+
+        let options = parse_args(args(&["-d", "inputs"])).unwrap();
+        assert_eq!(Source::Dir(PathBuf::from("inputs")), options.source);
+
+        let options = parse_args(args(&["bench", "6", "-r", "3", "--save", "b.txt"])).unwrap();
+        assert_eq!(Command::Bench, options.command);
+        assert_eq!(3, options.runs);
+        assert_eq!(Some(PathBuf::from("b.txt")), options.save);
+
+        let options = parse_args(args(&["bench", "--baseline", "b.txt", "--threshold", "5"]))
+            .unwrap();
+        assert_eq!(Some(PathBuf::from("b.txt")), options.baseline);
+        assert_eq!(5.0, options.threshold);
+
+        let options = parse_args(args(&["verify", "-a", "a.txt", "--record"])).unwrap();
+        assert_eq!(Command::Verify, options.command);
+        assert_eq!(PathBuf::from("a.txt"), options.answers);
+        assert!(options.record);
+
+        let options = parse_args(args(&["generate", "7", "--seed", "9", "--size", "50"])).unwrap();
+        assert_eq!(Command::Generate, options.command);
+        assert_eq!((9, Some(50)), (options.seed, options.size));
+
+        let options = parse_args(args(&["floors", "-i", "up.txt", "-f", "json"])).unwrap();
+        assert_eq!(Command::Floors, options.command);
+        assert_eq!(vec![1], options.days);
+
+        let options = parse_args(args(&["parcels", "--inline", "1x2x3", "-f", "csv"])).unwrap();
+        assert_eq!((Command::Parcels, vec![2]), (options.command, options.days));
+
+        assert_eq!(Command::List, parse_args(args(&["list"])).unwrap().command);
+        assert_eq!(Command::Help, parse_args(args(&["-h"])).unwrap().command);
+    }
+
+    #[test]
+    fn bad_args() {
+        assert!(parse_args(args(&["--part"])).is_err());
+        assert!(parse_args(args(&["--part", "3"])).is_err());
+        assert!(parse_args(args(&["--bogus"])).is_err());
+        assert!(parse_args(args(&["1", "2"])).is_err());
+        assert!(parse_args(args(&["1", "run"])).is_err());
+        assert!(parse_args(args(&["--input", "day1.txt"])).is_err());
+        assert!(parse_args(args(&["1-2", "--inline", "(("])).is_err());
+        assert!(parse_args(args(&["--format", "xml"])).is_err());
+        assert!(parse_args(args(&["generate"])).is_err());
+        assert!(parse_args(args(&["floors", "2"])).is_err());
+        assert!(parse_args(args(&["floors", "-f", "csv"])).is_err());
+        assert!(parse_args(args(&["parcels", "1"])).is_err());
+        assert!(parse_args(args(&["generate", "1", "--seed", "x"])).is_err());
+        assert!(parse_args(args(&["bench", "--runs", "0"])).is_err());
+        assert!(parse_args(args(&["-j", "0"])).is_err());
+        assert!(parse_args(args(&["bench", "--threshold", "-1"])).is_err());
+    }
+}
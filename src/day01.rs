@@ -0,0 +1,625 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::{self, Read};
+use std::str::Chars;
+use crate::pool;
+use crate::{ParseError, Result, Solution};
+
+pub mod edit;
+pub mod nesting;
+pub mod simulation;
+
+pub struct NotQuiteLisp;
+
+impl Solution for NotQuiteLisp {
+    const DAY: u8 = 1;
+    const NAME: &'static str = "not_quite_lisp";
+
+    type Input<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<&str> {
+        check_instructions(input, false)?;
+        Ok(input)
+    }
+
+    // The input has been checked, so it is all ASCII and the byte-oriented
+    // versions give the same answers as the scalar ones.
+    fn part1(input: &&str) -> i64 {
+        count_floor(input.as_bytes())
+    }
+
+    fn part2(input: &&str) -> Option<usize> {
+        find_basement(input.as_bytes())
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    trajectory(input).last().map_or(0, |(_, floor)| floor)
+}
+
+/// The position at which Santa first enters the basement, if he ever does.
+pub fn part2(input: &str) -> Option<usize> {
+    first_reached(input, -1)
+}
+
+/// The number of bytes handled together by the chunked scans. Each chunk
+/// is processed without branches, so the compiler can vectorise it.
+const CHUNK: usize = 64;
+
+fn step(b: u8) -> i32 {
+    (b == b'(') as i32 - (b == b')') as i32
+}
+
+/// The same as `part1`, but a chunk of bytes at a time. Bytes other than
+/// `(` and `)` are skipped.
+pub fn count_floor(input: &[u8]) -> i64 {
+    let chunks = input.chunks_exact(CHUNK);
+    let rest: i64 = chunks.remainder().iter().map(|&b| step(b) as i64).sum();
+
+    chunks.map(|chunk| chunk.iter().map(|&b| step(b)).sum::<i32>() as i64).sum::<i64>() + rest
+}
+
+/// The same as `part2`, but a chunk of bytes at a time. Positions count
+/// bytes, so this only matches `part2` for ASCII input.
+pub fn find_basement(input: &[u8]) -> Option<usize> {
+    find_floor_below(input, 0)
+}
+
+/// The same as `find_basement`, but split into blocks that are summarised
+/// on up to `threads` threads. Only the block holding the basement is then
+/// searched byte by byte.
+pub fn find_basement_parallel(input: &[u8], threads: usize) -> Option<usize> {
+    let threads = threads.max(1);
+    let block_len = input.len().div_ceil(threads).max(CHUNK);
+    let blocks: Vec<&[u8]> = input.chunks(block_len).collect();
+
+    let summaries = pool::parallel_map(&blocks, threads, |block| summary(block));
+    let mut floor = 0;
+
+    for (n, (block, (delta, lowest))) in blocks.iter().zip(summaries).enumerate() {
+        if floor + lowest <= -1 {
+            return find_floor_below(block, floor).map(|pos| n * block_len + pos);
+        }
+
+        floor += delta;
+    }
+
+    None
+}
+
+/// Find the position at which the elevator first reaches the basement,
+/// starting from `floor` at the beginning of `input`.
+fn find_floor_below(input: &[u8], mut floor: i64) -> Option<usize> {
+    for (n, chunk) in input.chunks(CHUNK).enumerate() {
+        let (delta, lowest) = chunk_summary(chunk);
+
+        // Only search a chunk byte by byte once we know the basement is in it.
+        if floor + lowest <= -1 {
+            for (i, &b) in chunk.iter().enumerate() {
+                floor += step(b) as i64;
+                if floor == -1 {
+                    return Some(n * CHUNK + i + 1);
+                }
+            }
+        }
+
+        floor += delta;
+    }
+
+    None
+}
+
+/// The net change of floor over `input`, and the lowest floor reached
+/// relative to the start of it.
+fn summary(input: &[u8]) -> (i64, i64) {
+    input.chunks(CHUNK).map(chunk_summary).fold((0, 0), |(level, lowest), (delta, low)| {
+        (level + delta, lowest.min(level + low))
+    })
+}
+
+/// The same as `summary`, for a single chunk.
+fn chunk_summary(chunk: &[u8]) -> (i64, i64) {
+    let mut level = 0;
+    let mut lowest = 0;
+
+    for &b in chunk {
+        level += step(b);
+        lowest = lowest.min(level);
+    }
+
+    (level as i64, lowest as i64)
+}
+
+/// Check that the input holds nothing but `(` and `)`, and report the first
+/// character that is not one of them. With `ignore_whitespace`, spaces and
+/// line breaks are allowed too, and are skipped when following the
+/// instructions.
+pub fn check_instructions(input: &str, ignore_whitespace: bool)
+    -> std::result::Result<(), ParseError> {
+
+    // Valid input is the common case, and is quick to check a byte at a time.
+    if input.bytes().all(|b| b == b'(' || b == b')') {
+        return Ok(());
+    }
+
+    let mut line = 1;
+    let mut column = 0;
+
+    for (i, c) in input.chars().enumerate() {
+        column += 1;
+
+        if c != '(' && c != ')' && !(ignore_whitespace && c.is_whitespace()) {
+            let reason = format!("invalid instruction at position {}", i + 1);
+            return Err(ParseError::new(column, &c.to_string(), reason).at(NotQuiteLisp::DAY, line));
+        }
+
+        if c == '\n' {
+            line += 1;
+            column = 0;
+        }
+    }
+
+    Ok(())
+}
+
+/// Follow the instructions, yielding `(position, floor)` after each one.
+/// Positions count characters from 1, as in the puzzle. Characters other
+/// than `(` and `)` do not move the elevator and are skipped.
+pub fn trajectory(input: &str) -> Trajectory<'_> {
+    Trajectory { chars: input.chars(), position: 0, floor: 0 }
+}
+
+#[derive(Clone, Debug)]
+pub struct Trajectory<'a> {
+    chars: Chars<'a>,
+    position: usize,
+    floor: i64,
+}
+
+impl Iterator for Trajectory<'_> {
+    type Item = (usize, i64);
+
+    fn next(&mut self) -> Option<(usize, i64)> {
+        for c in &mut self.chars {
+            self.position += 1;
+
+            match c {
+                '(' => self.floor += 1,
+                ')' => self.floor -= 1,
+                _ => continue,
+            }
+
+            return Some((self.position, self.floor));
+        }
+
+        None
+    }
+}
+
+/// The highest floor reached, counting the ground floor where Santa starts.
+pub fn highest_floor(input: &str) -> i64 {
+    trajectory(input).map(|(_, floor)| floor).fold(0, i64::max)
+}
+
+/// The lowest floor reached, counting the ground floor where Santa starts.
+pub fn lowest_floor(input: &str) -> i64 {
+    trajectory(input).map(|(_, floor)| floor).fold(0, i64::min)
+}
+
+/// The position at which `target` is first reached, or `None` if it never
+/// is. The ground floor is reached at position 0, before any instructions.
+pub fn first_reached(input: &str, target: i64) -> Option<usize> {
+    if target == 0 {
+        return Some(0);
+    }
+
+    trajectory(input).find(|&(_, floor)| floor == target).map(|(pos, _)| pos)
+}
+
+/// The position at which each floor is first reached. The ground floor is
+/// reached at position 0, before any instructions.
+pub fn first_visits(input: &str) -> BTreeMap<i64, usize> {
+    let mut visits = BTreeMap::new();
+    visits.insert(0, 0);
+
+    for (pos, floor) in trajectory(input) {
+        visits.entry(floor).or_insert(pos);
+    }
+
+    visits
+}
+
+/// How the elevator spends its time: how often each floor is visited, the
+/// longest runs of moves spent above and below ground, and how many times
+/// it crosses the ground floor from one side to the other.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FloorStats {
+    /// Every arrival at each floor, counting the start on the ground floor.
+    pub visits: BTreeMap<i64, usize>,
+    pub longest_above: usize,
+    pub longest_below: usize,
+    pub crossings: usize,
+}
+
+impl FloorStats {
+    pub fn new(input: &str) -> FloorStats {
+        let mut stats = FloorStats::default();
+        stats.visits.insert(0, 1);
+
+        let (mut above, mut below) = (0, 0);
+        let mut last_side = 0;
+
+        for (_, floor) in trajectory(input) {
+            *stats.visits.entry(floor).or_insert(0) += 1;
+
+            if floor > 0 {
+                above += 1;
+                below = 0;
+            } else if floor < 0 {
+                below += 1;
+                above = 0;
+            } else {
+                above = 0;
+                below = 0;
+            }
+
+            stats.longest_above = stats.longest_above.max(above);
+            stats.longest_below = stats.longest_below.max(below);
+
+            // Passing through the ground floor only counts as a crossing if
+            // the elevator carries on to the other side.
+            let side = floor.signum();
+            if side != 0 {
+                if side == -last_side {
+                    stats.crossings += 1;
+                }
+                last_side = side;
+            }
+        }
+
+        stats
+    }
+
+    pub fn table(&self) -> String {
+        let mut out = format!("{:>6}  {:>8}\n", "Floor", "Visits");
+
+        for (floor, visits) in self.visits.iter().rev() {
+            writeln!(out, "{:>6}  {:>8}", floor, visits).unwrap();
+        }
+
+        writeln!(out, "\nLongest stay above ground: {} moves", self.longest_above).unwrap();
+        writeln!(out, "Longest stay below ground: {} moves", self.longest_below).unwrap();
+        writeln!(out, "Ground floor crossings: {}", self.crossings).unwrap();
+        out
+    }
+
+    pub fn json(&self) -> String {
+        let visits: Vec<String> = self.visits.iter().map(|(floor, visits)| {
+            format!("{{\"floor\":{},\"visits\":{}}}", floor, visits)
+        }).collect();
+
+        format!(
+            "{{\"visits\":[{}],\"longest_above\":{},\"longest_below\":{},\"crossings\":{}}}",
+            visits.join(","), self.longest_above, self.longest_below, self.crossings
+        )
+    }
+}
+
+/// An elevator that follows instructions as they arrive, a chunk of bytes
+/// at a time, so that inputs too big to hold in memory can be followed.
+/// Positions count bytes from 1, which is the same as counting characters
+/// for valid instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elevator {
+    ignore_whitespace: bool,
+    position: u64,
+    floor: i64,
+    basement: Option<u64>,
+    line: usize,
+    column: usize,
+}
+
+impl Elevator {
+    pub fn new(ignore_whitespace: bool) -> Elevator {
+        Elevator {
+            ignore_whitespace,
+            position: 0,
+            floor: 0,
+            basement: None,
+            line: 1,
+            column: 0,
+        }
+    }
+
+    /// Follow every instruction from `reader` until it runs out.
+    pub fn read_from<R: Read>(mut reader: R, ignore_whitespace: bool) -> Result<Elevator> {
+        let mut elevator = Elevator::new(ignore_whitespace);
+        let mut buffer = vec![0; 64 * 1024];
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => return Ok(elevator),
+                Ok(n) => elevator.feed(&buffer[..n])?,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Follow the instructions in `chunk`, carrying on from any earlier
+    /// chunks. On an invalid byte the elevator stops just before it.
+    pub fn feed(&mut self, chunk: &[u8]) -> std::result::Result<(), ParseError> {
+        for &b in chunk {
+            match b {
+                b'(' => self.floor += 1,
+                b')' => self.floor -= 1,
+                b if self.ignore_whitespace && b.is_ascii_whitespace() => (),
+                b => {
+                    let text = if b.is_ascii() {
+                        (b as char).to_string()
+                    } else {
+                        format!("\\x{:02x}", b)
+                    };
+
+                    let reason = format!("invalid instruction at position {}", self.position + 1);
+                    let e = ParseError::new(self.column + 1, &text, reason);
+
+                    return Err(e.at(NotQuiteLisp::DAY, self.line));
+                },
+            }
+
+            self.position += 1;
+            self.column += 1;
+
+            if self.floor == -1 && self.basement.is_none() {
+                self.basement = Some(self.position);
+            }
+
+            if b == b'\n' {
+                self.line += 1;
+                self.column = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The number of bytes followed so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn floor(&self) -> i64 {
+        self.floor
+    }
+
+    /// The position at which the basement was first entered, if it has been.
+    pub fn basement(&self) -> Option<u64> {
+        self.basement
+    }
+}
+
+impl Default for Elevator {
+    fn default() -> Elevator {
+        Elevator::new(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    #[test]
+    fn final_floor() {
+        assert_eq!(0, part1("(())"));
+        assert_eq!(0, part1("()()"));
+        assert_eq!(3, part1("))((((("));
+        assert_eq!(-3, part1(")())())"));
+        assert_eq!(0, part1(""));
+    }
+
+    #[test]
+    fn basement() {
+        assert_eq!(Some(1), part2(")"));
+        assert_eq!(Some(5), part2("()())"));
+        assert_eq!(None, part2("(()"));
+    }
+
+    #[test]
+    fn first_reached_floors() {
+        assert_eq!(Some(0), first_reached("))", 0));
+        assert_eq!(Some(3), first_reached("(((", 3));
+        assert_eq!(Some(2), first_reached("))((", -2));
+        assert_eq!(None, first_reached("()()", 1 + 1));
+        assert_eq!(None, first_reached("", -1));
+    }
+
+    #[test]
+    fn strict_instructions() {
+        assert_eq!(Ok(()), check_instructions("(()))", false));
+        assert_eq!(Ok(()), check_instructions("", false));
+
+        let e = check_instructions("(()x)y", false).unwrap_err();
+        assert_eq!((1, 4, "x"), (e.line, e.column, e.text.as_str()));
+        assert_eq!("invalid instruction at position 4", e.reason);
+
+        // Characters, not bytes, are reported.
+        let e = check_instructions("é()", false).unwrap_err();
+        assert_eq!((1, "é"), (e.column, e.text.as_str()));
+        assert_eq!(2, check_instructions("(é)", false).unwrap_err().column);
+    }
+
+    #[test]
+    fn whitespace_ignored() {
+        assert!(check_instructions("(( ))\n()", false).is_err());
+        assert_eq!(Ok(()), check_instructions("(( ))\n()\n", true));
+
+        let e = check_instructions("((\n)]", true).unwrap_err();
+        assert_eq!((2, 2), (e.line, e.column));
+        assert_eq!("invalid instruction at position 5", e.reason);
+
+        assert!(NotQuiteLisp::parse("(\n)").is_err());
+    }
+
+    #[test]
+    fn streamed_in_chunks() {
+        let input = "(()))(()))(((";
+
+        for size in 1..=input.len() {
+            let mut elevator = Elevator::new(false);
+
+            for chunk in input.as_bytes().chunks(size) {
+                elevator.feed(chunk).unwrap();
+            }
+
+            assert_eq!(part1(input), elevator.floor());
+            assert_eq!(part2(input).map(|p| p as u64), elevator.basement());
+            assert_eq!(input.len() as u64, elevator.position());
+        }
+    }
+
+    #[test]
+    fn streamed_from_reader() {
+        let elevator = Elevator::read_from("(()\n)))\n".as_bytes(), true).unwrap();
+        assert_eq!((-2, Some(6)), (elevator.floor(), elevator.basement()));
+
+        match Elevator::read_from("(()\n)))".as_bytes(), false) {
+            Err(crate::Error::Parse(errors)) => {
+                assert_eq!("invalid instruction at position 4", errors[0].reason);
+            },
+            r => panic!("Expected a parse error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn streamed_errors() {
+        let mut elevator = Elevator::new(true);
+        elevator.feed(b"((\n(").unwrap();
+
+        let e = elevator.feed(b")\xff").unwrap_err();
+        assert_eq!((2, 3, "\\xff"), (e.line, e.column, e.text.as_str()));
+        assert_eq!("invalid instruction at position 6", e.reason);
+        assert_eq!((5, 2), (elevator.position(), elevator.floor()));
+
+        let e = Elevator::default().feed(b"(x").unwrap_err();
+        assert_eq!(
+            "day 1, line 1, column 2: invalid instruction at position 2: \"x\"", e.to_string()
+        );
+    }
+
+    #[test]
+    fn chunked_matches_scalar() {
+        let mut rng = Rng::new(15);
+
+        for len in (0..300).chain([CHUNK * 8, CHUNK * 8 + 1, 10_000]) {
+            let mut input = generate::instructions(&mut rng, len);
+
+            // Start some inputs high up, so the basement is reached late.
+            if rng.chance(30) {
+                input.insert_str(0, &"(".repeat(rng.below(40)));
+            }
+
+            assert_eq!(part1(&input), count_floor(input.as_bytes()), "{}", input);
+            assert_eq!(part2(&input), find_basement(input.as_bytes()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parallel_matches_scalar() {
+        let mut rng = Rng::new(16);
+
+        for len in (0..200).chain([CHUNK * 8 + 3, 20_000]) {
+            let mut input = generate::instructions(&mut rng, len);
+            input.insert_str(0, &"(".repeat(rng.below(60)));
+
+            for threads in [0, 1, 2, 3, 8] {
+                assert_eq!(
+                    part2(&input), find_basement_parallel(input.as_bytes(), threads),
+                    "{} threads: {}", threads, input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn block_summaries() {
+        assert_eq!((0, 0), summary(b""));
+        assert_eq!((-2, -3), summary(b"())))("));
+
+        // Spread over two chunks.
+        let input = format!("))({}", "(".repeat(CHUNK - 1));
+        assert_eq!((CHUNK as i64 - 2, -2), summary(input.as_bytes()));
+    }
+
+    #[test]
+    fn chunked_skips_other_bytes() {
+        let input = "((x))\n)y)";
+
+        assert_eq!(part1(input), count_floor(input.as_bytes()));
+        assert_eq!(part2(input), find_basement(input.as_bytes()));
+        assert_eq!(Some(7), find_basement(input.as_bytes()));
+    }
+
+    #[test]
+    fn floor_stats() {
+        // Floors: 1 2 1 0 -1 -2 -1 0 1 0
+        let stats = FloorStats::new("(())))((()");
+        let visits: Vec<(i64, usize)> = stats.visits.clone().into_iter().collect();
+
+        assert_eq!(vec![(-2, 1), (-1, 2), (0, 4), (1, 3), (2, 1)], visits);
+        assert_eq!((3, 3), (stats.longest_above, stats.longest_below));
+        assert_eq!(2, stats.crossings);
+
+        // Touching the ground and going back up is not a crossing.
+        assert_eq!(0, FloorStats::new("()(()").crossings);
+        assert_eq!(1, FloorStats::new("").visits[&0]);
+    }
+
+    #[test]
+    fn floor_stats_output() {
+        let stats = FloorStats::new("())");
+
+        assert_eq!(
+            "{\"visits\":[{\"floor\":-1,\"visits\":1},{\"floor\":0,\"visits\":2},\
+             {\"floor\":1,\"visits\":1}],\"longest_above\":1,\"longest_below\":1,\
+             \"crossings\":1}",
+            stats.json()
+        );
+
+        let table = stats.table();
+        assert!(table.starts_with(" Floor    Visits\n     1         1\n     0         2\n"));
+        assert!(table.ends_with("Ground floor crossings: 1\n"));
+    }
+
+    #[test]
+    fn trajectories() {
+        let steps: Vec<(usize, i64)> = trajectory("(()\n)").collect();
+        assert_eq!(vec![(1, 1), (2, 2), (3, 1), (5, 0)], steps);
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!((2, -1), (highest_floor("(()))"), lowest_floor("(()))")));
+        assert_eq!((0, -2), (highest_floor("))"), lowest_floor("))")));
+        assert_eq!((0, 0), (highest_floor(""), lowest_floor("")));
+    }
+
+    #[test]
+    fn first_visited() {
+        let visits = first_visits("(()))(");
+        let expected: Vec<(i64, usize)> = vec![(-1, 5), (0, 0), (1, 1), (2, 2)];
+
+        assert_eq!(expected, visits.into_iter().collect::<Vec<_>>());
+    }
+}
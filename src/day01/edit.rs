@@ -0,0 +1,179 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+// The fewest changes to a set of instructions that give a particular
+// outcome. Flipping a bracket moves the final floor by two and costs the
+// same as inserting or deleting one, which only move it by one, so flips
+// are used wherever there is a bracket to flip.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditKind {
+    /// Swap the bracket for the other one.
+    Flip,
+    /// Insert the given bracket before the position.
+    Insert(char),
+    Delete,
+}
+
+/// A change at a position in the original instructions. Positions count
+/// characters from 1; an insertion may also be at one past the end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Edit {
+    pub position: usize,
+    pub kind: EditKind,
+}
+
+/// The fewest edits that leave Santa on `target` once every instruction
+/// has been followed. Flips are made as early in the instructions as
+/// possible, and any insertions go at the end.
+pub fn edits_to_floor(input: &str, target: i64) -> Vec<Edit> {
+    let floor = super::count_floor(input.as_bytes());
+    let (wrong, right) = if target > floor { (')', '(') } else { ('(', ')') };
+    let needed = (target - floor).unsigned_abs() as usize;
+
+    let mut edits: Vec<Edit> = input.chars().enumerate()
+        .filter(|&(_, c)| c == wrong)
+        .take(needed / 2)
+        .map(|(i, _)| Edit { position: i + 1, kind: EditKind::Flip })
+        .collect();
+
+    let end = input.chars().count() + 1;
+    let extra = needed - 2 * edits.len();
+    edits.extend((0..extra).map(|_| Edit { position: end, kind: EditKind::Insert(right) }));
+
+    edits
+}
+
+/// The fewest edits that keep Santa out of the basement. Each flip is made
+/// at the point he would otherwise enter it, and lifts every later floor
+/// by two, which is as much as any single edit can.
+pub fn edits_to_avoid_basement(input: &str) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    let mut floor = 0;
+
+    for (i, c) in input.chars().enumerate() {
+        match c {
+            '(' => floor += 1,
+            ')' => floor -= 1,
+            _ => continue,
+        }
+
+        if floor == -1 {
+            edits.push(Edit { position: i + 1, kind: EditKind::Flip });
+            floor = 1;
+        }
+    }
+
+    edits
+}
+
+/// Make the edits, which must be in position order, to the instructions.
+pub fn apply(input: &str, edits: &[Edit]) -> String {
+    let mut out = String::with_capacity(input.len() + edits.len());
+    let mut edits = edits.iter().peekable();
+
+    for (i, c) in input.chars().enumerate() {
+        let mut keep = true;
+
+        while let Some(edit) = edits.next_if(|e| e.position == i + 1) {
+            match edit.kind {
+                EditKind::Insert(b) => out.push(b),
+                EditKind::Delete => keep = false,
+                EditKind::Flip => {
+                    out.push(if c == '(' { ')' } else { '(' });
+                    keep = false;
+                },
+            }
+        }
+
+        if keep {
+            out.push(c);
+        }
+    }
+
+    // Anything left can only be an insertion at the end.
+    for edit in edits {
+        if let EditKind::Insert(b) = edit.kind {
+            out.push(b);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01;
+
+    fn flip(position: usize) -> Edit {
+        Edit { position, kind: EditKind::Flip }
+    }
+
+    #[test]
+    fn to_floor() {
+        assert!(edits_to_floor("(())", 0).is_empty());
+        assert_eq!(vec![flip(2)], edits_to_floor("())", 1));
+        assert_eq!(vec![flip(1), flip(2)], edits_to_floor("(()", -3));
+
+        let insert = Edit { position: 3, kind: EditKind::Insert('(') };
+        assert_eq!(vec![flip(2), insert], edits_to_floor("()", 3));
+        assert_eq!(vec![insert, insert], edits_to_floor("((", 4));
+    }
+
+    #[test]
+    fn avoid_basement() {
+        assert!(edits_to_avoid_basement("(()").is_empty());
+        assert_eq!(vec![flip(1)], edits_to_avoid_basement(")"));
+        assert_eq!(vec![flip(3), flip(5), flip(7)], edits_to_avoid_basement("())))))"));
+    }
+
+    #[test]
+    fn applied() {
+        let edits = [
+            flip(1),
+            Edit { position: 2, kind: EditKind::Delete },
+            Edit { position: 4, kind: EditKind::Insert(')') },
+        ];
+
+        assert_eq!(")))", apply("())", &edits));
+        assert_eq!("(())", apply("())", &[Edit { position: 1, kind: EditKind::Insert('(') }]));
+        assert_eq!("x", apply("x", &[]));
+    }
+
+    #[test]
+    fn fewest_edits() {
+        // Each flip covers two floors, as long as there are brackets going
+        // the wrong way to flip, and inserts cover whatever is left.
+        let cases = [
+            ("", 3, 3),
+            ("", -2, 2),
+            ("(((", 0, 2),
+            ("(((", -5, 5),
+            ("()()", 4, 2),
+            ("))", 5, 5),
+            ("(()))(", -1, 1),
+            ("())(", 0, 0),
+        ];
+
+        for (input, target, fewest) in cases {
+            let edits = edits_to_floor(input, target);
+
+            assert_eq!(target, day01::count_floor(apply(input, &edits).as_bytes()), "{}", input);
+            assert_eq!(fewest, edits.len(), "{} to {}", input, target);
+        }
+
+        // One flip for every two floors below ground.
+        for (input, fewest) in [("()", 0), (")", 1), ("()))", 1), ("))))", 2), ("())))))", 3)] {
+            let edits = edits_to_avoid_basement(input);
+
+            assert_eq!(None, day01::part2(&apply(input, &edits)), "{}", input);
+            assert_eq!(fewest, edits.len(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+// The instructions are brackets, so they can be read as nested pairs too.
+// Each matched pair leaves the elevator on the floor it started from, and
+// each unmatched bracket moves it for good: every unmatched `)` takes it to
+// a floor lower than it has been before, so the first is the basement.
+
+/// The bracket structure of a set of instructions. Positions count
+/// characters from 1, as in the puzzle, and other characters are skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Nesting {
+    pub max_depth: usize,
+    /// Every matched pair, as the positions of its `(` and `)`, in the order
+    /// they close.
+    pub pairs: Vec<(usize, usize)>,
+    pub unmatched_open: Vec<usize>,
+    pub unmatched_close: Vec<usize>,
+    /// The longest balanced stretches between unmatched brackets, as
+    /// inclusive `(first, last)` positions.
+    pub balanced: Vec<(usize, usize)>,
+}
+
+impl Nesting {
+    pub fn new(input: &str) -> Nesting {
+        let mut nesting = Nesting::default();
+        let mut open = Vec::new();
+        let mut brackets = Vec::new();
+
+        for (i, c) in input.chars().enumerate() {
+            let pos = i + 1;
+
+            match c {
+                '(' => {
+                    open.push(pos);
+                    nesting.max_depth = nesting.max_depth.max(open.len());
+                },
+                ')' => match open.pop() {
+                    Some(first) => nesting.pairs.push((first, pos)),
+                    None => nesting.unmatched_close.push(pos),
+                },
+                _ => continue,
+            }
+
+            brackets.push(pos);
+        }
+
+        nesting.unmatched_open = open;
+        nesting.balanced = nesting.stretches(&brackets);
+        nesting
+    }
+
+    // No pair can span an unmatched bracket, so the brackets between
+    // unmatched ones are all matched with each other.
+    fn stretches(&self, brackets: &[usize]) -> Vec<(usize, usize)> {
+        let mut stretches = Vec::new();
+        let mut current: Option<(usize, usize)> = None;
+
+        for &pos in brackets {
+            let unmatched = self.unmatched_close.binary_search(&pos).is_ok()
+                || self.unmatched_open.binary_search(&pos).is_ok();
+
+            if unmatched {
+                stretches.extend(current.take());
+            } else {
+                current = Some((current.map_or(pos, |(first, _)| first), pos));
+            }
+        }
+
+        stretches.extend(current);
+        stretches
+    }
+
+    /// The floor the elevator ends on: matched pairs cancel out.
+    pub fn final_floor(&self) -> i64 {
+        self.unmatched_open.len() as i64 - self.unmatched_close.len() as i64
+    }
+
+    /// The lowest floor reached, one below ground for each unmatched `)`.
+    pub fn lowest_floor(&self) -> i64 {
+        -(self.unmatched_close.len() as i64)
+    }
+
+    /// The position at which the basement is first entered, if it is.
+    pub fn basement(&self) -> Option<usize> {
+        self.unmatched_close.first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01;
+
+    #[test]
+    fn structure() {
+        let nesting = Nesting::new("(()))(()((");
+
+        assert_eq!(3, nesting.max_depth);
+        assert_eq!(vec![(2, 3), (1, 4), (7, 8)], nesting.pairs);
+        assert_eq!(vec![5], nesting.unmatched_close);
+        assert_eq!(vec![6, 9, 10], nesting.unmatched_open);
+        assert_eq!(vec![(1, 4), (7, 8)], nesting.balanced);
+    }
+
+    #[test]
+    fn balanced_stretches() {
+        assert_eq!(vec![(1, 4)], Nesting::new("()()").balanced);
+        assert_eq!(vec![(2, 3), (5, 8)], Nesting::new(")())(())").balanced);
+        assert_eq!(vec![(2, 3)], Nesting::new("(()").balanced);
+        assert!(Nesting::new("))((").balanced.is_empty());
+        assert_eq!(Nesting::default(), Nesting::new(""));
+    }
+
+    #[test]
+    fn pairs_return_to_the_same_floor() {
+        let cases = [
+            "", "(", ")", "()", ")(", "(()(()(", "))(((", "())())", "(()))(()((", "()())(()))",
+        ];
+
+        for input in cases {
+            let nesting = Nesting::new(input);
+
+            // Floors before each position, starting on the ground floor.
+            let mut floors = vec![0];
+            floors.extend(day01::trajectory(input).map(|(_, floor)| floor));
+
+            for &(first, last) in &nesting.pairs {
+                assert_eq!(floors[first - 1], floors[last], "{}", input);
+            }
+
+            for &(first, last) in &nesting.balanced {
+                assert_eq!(floors[first - 1], floors[last], "{}", input);
+            }
+
+            assert_eq!(day01::part1(input), nesting.final_floor());
+            assert_eq!(day01::part2(input), nesting.basement());
+            assert_eq!(day01::lowest_floor(input), nesting.lowest_floor());
+        }
+    }
+}
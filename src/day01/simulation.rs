@@ -0,0 +1,296 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Something that happened to an elevator, and when. Times are in ticks
+/// from the start of the simulation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Arrived { time: u64, elevator: usize, floor: i64 },
+    /// The elevator went down from the ground floor into the basement.
+    EnteredBasement { time: u64, elevator: usize },
+    /// The next floor is full, so the elevator has to wait for room.
+    Waiting { time: u64, elevator: usize, floor: i64 },
+    Finished { time: u64, elevator: usize, floor: i64 },
+    /// Every elevator that has instructions left is waiting for another.
+    Stalled { time: u64 },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Arrived { time, elevator, floor } => {
+                write!(f, "{}: elevator {} arrived at floor {}", time, elevator, floor)
+            },
+            Event::EnteredBasement { time, elevator } => {
+                write!(f, "{}: elevator {} entered the basement", time, elevator)
+            },
+            Event::Waiting { time, elevator, floor } => {
+                write!(f, "{}: elevator {} waiting for room on floor {}", time, elevator, floor)
+            },
+            Event::Finished { time, elevator, floor } => {
+                write!(f, "{}: elevator {} finished on floor {}", time, elevator, floor)
+            },
+            Event::Stalled { time } => write!(f, "{}: stalled, every elevator is waiting", time),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Elevator {
+    moves: Vec<i64>,
+    next: usize,
+    floor: i64,
+    move_time: u64,
+    arriving: Option<u64>,
+    waiting: bool,
+    finished: bool,
+}
+
+/// Several elevators following the usual instructions at once. Each move
+/// takes an elevator's own move time, and no floor may hold more than
+/// `capacity` elevators, counting those on their way to it. The ground
+/// floor is the lobby, and has room for everyone.
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    elevators: Vec<Elevator>,
+    capacity: Option<usize>,
+    occupancy: HashMap<i64, usize>,
+}
+
+impl Simulation {
+    pub fn new(capacity: Option<usize>) -> Simulation {
+        Simulation { elevators: Vec::new(), capacity, occupancy: HashMap::new() }
+    }
+
+    /// Split one set of instructions between `elevators` elevators, which
+    /// take turns: the first instruction is for elevator 0, the second for
+    /// elevator 1, and so on.
+    pub fn interleaved(input: &str, elevators: usize, move_time: u64, capacity: Option<usize>)
+        -> Simulation {
+
+        let elevators = elevators.max(1);
+        let mut tracks = vec![String::new(); elevators];
+        let brackets = input.chars().filter(|&c| c == '(' || c == ')');
+
+        for (i, c) in brackets.enumerate() {
+            tracks[i % elevators].push(c);
+        }
+
+        let mut sim = Simulation::new(capacity);
+        for track in &tracks {
+            sim.add_elevator(track, move_time);
+        }
+
+        sim
+    }
+
+    /// Add an elevator on the ground floor with its own instructions, and
+    /// return its number. Moves take at least one tick.
+    pub fn add_elevator(&mut self, instructions: &str, move_time: u64) -> usize {
+        let moves = instructions.chars().filter_map(|c| match c {
+            '(' => Some(1),
+            ')' => Some(-1),
+            _ => None,
+        }).collect();
+
+        *self.occupancy.entry(0).or_insert(0) += 1;
+        self.elevators.push(Elevator {
+            moves,
+            next: 0,
+            floor: 0,
+            move_time: move_time.max(1),
+            arriving: None,
+            waiting: false,
+            finished: false,
+        });
+
+        self.elevators.len() - 1
+    }
+
+    pub fn floor(&self, elevator: usize) -> i64 {
+        self.elevators[elevator].floor
+    }
+
+    /// Run every elevator until it runs out of instructions, or until none
+    /// of them can move. The events come out in time order. Within a tick,
+    /// arrivals (each followed by any basement entry) come first, then
+    /// elevators that start waiting, then those that finish, each group in
+    /// order of elevator number. A stall is always the last event.
+    pub fn run(&mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        let mut time = 0;
+
+        loop {
+            self.arrive(time, &mut events);
+            self.depart(time, &mut events);
+
+            for (n, e) in self.elevators.iter_mut().enumerate() {
+                if !e.finished && e.arriving.is_none() && e.next == e.moves.len() {
+                    e.finished = true;
+                    events.push(Event::Finished { time, elevator: n, floor: e.floor });
+                }
+            }
+
+            match self.elevators.iter().filter_map(|e| e.arriving).min() {
+                Some(t) => time = t,
+                None => {
+                    if self.elevators.iter().any(|e| e.waiting) {
+                        events.push(Event::Stalled { time });
+                    }
+                    return events;
+                },
+            }
+        }
+    }
+
+    fn arrive(&mut self, time: u64, events: &mut Vec<Event>) {
+        for (n, e) in self.elevators.iter_mut().enumerate() {
+            if e.arriving != Some(time) {
+                continue;
+            }
+
+            e.arriving = None;
+            events.push(Event::Arrived { time, elevator: n, floor: e.floor });
+
+            if e.floor == -1 && e.moves[e.next - 1] == -1 {
+                events.push(Event::EnteredBasement { time, elevator: n });
+            }
+        }
+    }
+
+    // Keep going round until nobody else can leave, as each departure may
+    // make room for an elevator that was waiting.
+    fn depart(&mut self, time: u64, events: &mut Vec<Event>) {
+        let mut moved = true;
+
+        while moved {
+            moved = false;
+
+            for n in 0..self.elevators.len() {
+                let e = &self.elevators[n];
+                if e.arriving.is_some() || e.next == e.moves.len() {
+                    continue;
+                }
+
+                let (from, to) = (e.floor, e.floor + e.moves[e.next]);
+
+                if !self.has_room(to) {
+                    if !e.waiting {
+                        self.elevators[n].waiting = true;
+                        events.push(Event::Waiting { time, elevator: n, floor: to });
+                    }
+                    continue;
+                }
+
+                *self.occupancy.get_mut(&from).expect("Every elevator is on a floor.") -= 1;
+                *self.occupancy.entry(to).or_insert(0) += 1;
+
+                let e = &mut self.elevators[n];
+                e.floor = to;
+                e.next += 1;
+                e.waiting = false;
+                e.arriving = Some(time + e.move_time);
+                moved = true;
+            }
+        }
+    }
+
+    fn has_room(&self, floor: i64) -> bool {
+        match self.capacity {
+            Some(c) if floor != 0 => self.occupancy.get(&floor).copied().unwrap_or(0) < c,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01;
+
+    fn basement_times(events: &[Event]) -> Vec<(u64, usize)> {
+        events.iter().filter_map(|e| match *e {
+            Event::EnteredBasement { time, elevator } => Some((time, elevator)),
+            _ => None,
+        }).collect()
+    }
+
+    #[test]
+    fn single_elevator() {
+        let input = "(()))())";
+        let events = Simulation::interleaved(input, 1, 3, None).run();
+
+        let first = basement_times(&events)[0];
+        assert_eq!((3 * day01::part2(input).unwrap() as u64, 0), first);
+        assert_eq!(2, basement_times(&events).len());
+        assert_eq!(
+            Some(&Event::Finished { time: 24, elevator: 0, floor: day01::part1(input) }),
+            events.last()
+        );
+    }
+
+    #[test]
+    fn interleaved_tracks() {
+        let mut sim = Simulation::interleaved("()()))", 2, 1, None);
+        sim.run();
+
+        // Elevator 0 follows "(()", and elevator 1 follows ")))".
+        assert_eq!((1, -3), (sim.floor(0), sim.floor(1)));
+    }
+
+    #[test]
+    fn own_speeds() {
+        let mut sim = Simulation::new(None);
+        sim.add_elevator("((", 5);
+        sim.add_elevator(")", 2);
+
+        assert_eq!(
+            vec![
+                Event::Arrived { time: 2, elevator: 1, floor: -1 },
+                Event::EnteredBasement { time: 2, elevator: 1 },
+                Event::Finished { time: 2, elevator: 1, floor: -1 },
+                Event::Arrived { time: 5, elevator: 0, floor: 1 },
+                Event::Arrived { time: 10, elevator: 0, floor: 2 },
+                Event::Finished { time: 10, elevator: 0, floor: 2 },
+            ],
+            sim.run()
+        );
+    }
+
+    #[test]
+    fn waiting_for_room() {
+        let mut sim = Simulation::new(Some(1));
+        sim.add_elevator("((", 2);
+        sim.add_elevator("(", 1);
+
+        // Elevator 1 can only leave once elevator 0 has moved on.
+        let events = sim.run();
+        assert_eq!(Event::Waiting { time: 0, elevator: 1, floor: 1 }, events[0]);
+        assert!(events.contains(&Event::Finished { time: 3, elevator: 1, floor: 1 }));
+        assert_eq!(Some(&Event::Finished { time: 4, elevator: 0, floor: 2 }), events.last());
+        assert_eq!((2, 1), (sim.floor(0), sim.floor(1)));
+    }
+
+    #[test]
+    fn stalled() {
+        // Elevator 0 comes back down to floor 1 just as elevator 1 wants to
+        // go up to floor 2, so neither can move.
+        let mut sim = Simulation::new(Some(1));
+        sim.add_elevator("(()", 1);
+        sim.add_elevator("((", 1);
+
+        let events = sim.run();
+        assert!(events.contains(&Event::Waiting { time: 2, elevator: 0, floor: 1 }));
+        assert!(events.contains(&Event::Waiting { time: 2, elevator: 1, floor: 2 }));
+        assert_eq!(Some(&Event::Stalled { time: 2 }), events.last());
+        assert_eq!("2: stalled, every elevator is waiting", events.last().unwrap().to_string());
+    }
+}
@@ -0,0 +1,328 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use crate::{Error, ParseError, Result, Solution};
+use crate::error;
+
+pub mod packing;
+pub mod report;
+pub mod wrapping;
+
+use self::wrapping::{Paper, Ribbon, WrappingPolicy};
+
+pub struct NoMath;
+
+impl Solution for NoMath {
+    const DAY: u8 = 2;
+    const NAME: &'static str = "no_math";
+
+    type Input<'a> = Vec<Parcel>;
+    type Part1 = Option<u64>;
+    type Part2 = Option<u64>;
+
+    fn parse(input: &str) -> Result<Vec<Parcel>> {
+        parse_input(input)
+    }
+
+    // Each total is checked on its own, so one part has no answer when its
+    // total is too big to count without losing the other.
+    fn part1(parcels: &Vec<Parcel>) -> Option<u64> {
+        part1(parcels).ok()
+    }
+
+    fn part2(parcels: &Vec<Parcel>) -> Option<u64> {
+        part2(parcels).ok()
+    }
+}
+
+/// The total wrapping paper needed, or an error if it is too big for a u64.
+pub fn part1(parcels: &[Parcel]) -> Result<u64> {
+    total(parcels, &Paper)
+}
+
+/// The total ribbon needed, or an error if it is too big for a u64.
+pub fn part2(parcels: &[Parcel]) -> Result<u64> {
+    total(parcels, &Ribbon)
+}
+
+/// The total that `policy` needs for every parcel, or an error if it is
+/// too big for a u64.
+pub fn total<P: WrappingPolicy + ?Sized>(parcels: &[Parcel], policy: &P) -> Result<u64> {
+    let mut sum: u64 = 0;
+
+    for parcel in parcels {
+        sum = policy.amount(parcel)
+            .and_then(|a| u64::try_from(a).ok())
+            .and_then(|a| sum.checked_add(a))
+            .ok_or_else(|| {
+                Error::invalid(NoMath::DAY, format!("too much {} to count", policy.material()))
+            })?;
+    }
+
+    Ok(sum)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Parcel>> {
+    error::parse_lines(NoMath::DAY, input, Parcel::from_string)
+}
+
+/// A parcel's dimensions. Everything worked out from them is a u128, which
+/// is wide enough for any parcel, so none of it can overflow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parcel {
+    length: u32,
+    width: u32,
+    height: u32,
+    smallest_side: u128,
+    shortest_perimeter: u128,
+}
+
+impl Parcel {
+    pub fn new(l: u32, w: u32, h: u32) -> Parcel {
+        let (l, w, h) = (l as u128, w as u128, h as u128);
+        let sides = [l * w, w * h, h * l];
+        let perimeters = [l + w, w + h, h + l];
+
+        Parcel {
+            length: l as u32,
+            width: w as u32,
+            height: h as u32,
+            smallest_side: match sides.iter().min() {
+                Some(s) => *s,
+                None => 0,
+            },
+            shortest_perimeter: match perimeters.iter().min() {
+                Some(s) => *s * 2,
+                None => 0,
+            },
+        }
+    }
+
+    /// Parse a parcel's dimensions, such as `2x3x4`. The separators may
+    /// be `x`, `X`, `*` or `×`, with any amount of whitespace around them.
+    pub fn from_string(s: &str) -> std::result::Result<Parcel, ParseError> {
+        if s.trim().is_empty() {
+            return Err(ParseError::new(1, s, "missing dimensions"));
+        }
+
+        let mut dims = Vec::with_capacity(3);
+        let mut column = 1;
+
+        for dim in s.split(SEPARATORS) {
+            let start = column + dim.chars().take_while(|c| c.is_whitespace()).count();
+            let trimmed = dim.trim();
+
+            match trimmed.parse() {
+                Ok(d) => dims.push(d),
+                Err(_) => return Err(ParseError::new(start, trimmed, "invalid dimension")),
+            }
+
+            column += dim.chars().count() + 1;
+        }
+
+        if dims.len() != 3 {
+            return Err(ParseError::new(1, s, "expected three dimensions"));
+        }
+
+        Ok(Parcel::new(dims[0], dims[1], dims[2]))
+    }
+
+    /// The length, width and height, in the order they were given.
+    pub fn dimensions(&self) -> (u32, u32, u32) {
+        (self.length, self.width, self.height)
+    }
+
+    fn wide_dimensions(&self) -> (u128, u128, u128) {
+        (self.length as u128, self.width as u128, self.height as u128)
+    }
+
+    pub fn area(&self) -> u128 {
+        let (l, w, h) = self.wide_dimensions();
+        2 * ((l * w) + (w * h) + (h * l))
+    }
+
+    pub fn volume(&self) -> u128 {
+        let (l, w, h) = self.wide_dimensions();
+        l * w * h
+    }
+
+    pub fn smallest_side(&self) -> u128 {
+        self.smallest_side
+    }
+
+    pub fn shortest_perimeter(&self) -> u128 {
+        self.shortest_perimeter
+    }
+
+    pub fn wrap_required(&self) -> u128 {
+        self.area() + self.smallest_side
+    }
+
+    pub fn ribbon_required(&self) -> u128 {
+        self.shortest_perimeter + self.volume()
+    }
+}
+
+const SEPARATORS: &[char] = &['x', 'X', '*', '×'];
+
+// A parcel parsed on its own is a one-line day 2 input.
+impl FromStr for Parcel {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Parcel, ParseError> {
+        Parcel::from_string(s).map_err(|e| e.at(NoMath::DAY, 1))
+    }
+}
+
+impl TryFrom<&str> for Parcel {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> std::result::Result<Parcel, ParseError> {
+        s.parse()
+    }
+}
+
+/// Parcels always print with an `x` between dimensions, whichever separator
+/// they were parsed with.
+impl fmt::Display for Parcel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}x{}", self.length, self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parcel_new() {
+        let p = Parcel::new(2, 3, 4);
+
+        assert_eq!(2, p.length);
+        assert_eq!(3, p.width);
+        assert_eq!(4, p.height);
+        assert_eq!(6, p.smallest_side);
+        assert_eq!(10, p.shortest_perimeter);
+    }
+
+    #[test]
+    fn parcel_from_string() {
+        let p = Parcel::from_string("2x3x4").unwrap();
+
+        assert_eq!(2, p.length);
+        assert_eq!(3, p.width);
+        assert_eq!(4, p.height);
+        assert_eq!(6, p.smallest_side);
+        assert_eq!(10, p.shortest_perimeter);
+    }
+
+    #[test]
+    fn parcel_to_string() {
+        assert_eq!("2x3x4", Parcel::new(2, 3, 4).to_string());
+    }
+
+    #[test]
+    fn parcel_bad_strings() {
+        assert_eq!(
+            Err(ParseError::new(3, "", "invalid dimension")), Parcel::from_string("2xx4")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, "2x3", "expected three dimensions")),
+            Parcel::from_string("2x3")
+        );
+        assert_eq!(
+            Err(ParseError::new(5, "a", "invalid dimension")), Parcel::from_string("2x3xa")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, " ", "missing dimensions")), Parcel::from_string(" ")
+        );
+        assert_eq!(
+            Err(ParseError::new(9, "-4", "invalid dimension")), Parcel::from_string("2 x 3 x -4")
+        );
+        assert!(Parcel::from_string("2x3x4x5").is_err());
+    }
+
+    #[test]
+    fn parcel_flexible_strings() {
+        let p = Parcel::new(2, 3, 4);
+
+        assert_eq!(Ok(p.clone()), "2 x 3 x 4".parse());
+        assert_eq!(Ok(p.clone()), "2X3X4".parse());
+        assert_eq!(Ok(p.clone()), " 2*3 × 4\t".parse());
+        assert_eq!(Ok(p), Parcel::try_from("2×3x4"));
+
+        assert_eq!(
+            "day 2, line 1, column 1: expected three dimensions: \"2x3\"",
+            "2x3".parse::<Parcel>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "day 2, line 1, column 3: invalid dimension: \"y\"",
+            Parcel::try_from("2xyx4").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn parse_all_bad_lines() {
+        match parse_input("2x3x4\n2x3\n1x1x1\n\n") {
+            Err(crate::Error::Parse(errors)) => {
+                assert_eq!(vec![2, 4], errors.iter().map(|e| e.line).collect::<Vec<_>>());
+                assert!(errors.iter().all(|e| e.day == 2));
+            },
+            _ => panic!("Expected parse errors."),
+        }
+    }
+
+    #[test]
+    fn parcel_area_and_wrap() {
+        let p = Parcel::new(2, 3, 4);
+
+        assert_eq!(52, p.area());
+        assert_eq!(58, p.wrap_required());
+    }
+
+    #[test]
+    fn parcel_volume_and_wrap() {
+        let p = Parcel::new(2, 3, 4);
+
+        assert_eq!(24, p.volume());
+        assert_eq!(34, p.ribbon_required());
+    }
+
+    #[test]
+    fn huge_parcels() {
+        let max = u32::MAX as u128;
+        let p = Parcel::new(u32::MAX, u32::MAX, u32::MAX);
+
+        assert_eq!(max * max * max, p.volume());
+        assert_eq!(7 * max * max, p.wrap_required());
+        assert_eq!(4 * max + max * max * max, p.ribbon_required());
+    }
+
+    #[test]
+    fn totals_overflow() {
+        let order = vec![Parcel::new(1 << 20, 1 << 20, 1 << 20); 16];
+        assert_eq!(7 << 40, part1(&order[..1]).unwrap());
+        assert_eq!((1 << 60) + (4 << 20), part2(&order[..1]).unwrap());
+
+        // The ribbon for sixteen of these is more than a u64 can hold.
+        assert!(part1(&order).is_ok());
+        assert_eq!(
+            "day 2: too much ribbon to count", part2(&order).unwrap_err().to_string()
+        );
+
+        let huge = vec![Parcel::new(u32::MAX, u32::MAX, u32::MAX)];
+        assert!(part2(&huge).is_err());
+        assert_eq!(None, NoMath::part2(&huge));
+
+        assert_eq!(Some(7 << 44), NoMath::part1(&order));
+        assert_eq!(None, NoMath::part2(&order));
+    }
+}
@@ -0,0 +1,281 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+// Pack parcels into crates, first fit decreasing: the biggest parcels go
+// first, each into the first crate with room for it. Within a crate a
+// parcel may go at any "extreme point", the corners just beyond parcels
+// that are already packed, nearest the floor and back wall first.
+
+use super::Parcel;
+
+/// The six ways a parcel can be turned so that its edges line up with the
+/// crate's. Each one says which of the parcel's dimensions lie along the
+/// crate's length, width and height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    Lwh,
+    Lhw,
+    Wlh,
+    Whl,
+    Hlw,
+    Hwl,
+}
+
+impl Rotation {
+    pub const ALL: [Rotation; 6] = [
+        Rotation::Lwh, Rotation::Lhw, Rotation::Wlh, Rotation::Whl, Rotation::Hlw, Rotation::Hwl,
+    ];
+
+    /// The size of the parcel along each of the crate's axes once turned.
+    pub fn apply(&self, (l, w, h): (u32, u32, u32)) -> (u32, u32, u32) {
+        match self {
+            Rotation::Lwh => (l, w, h),
+            Rotation::Lhw => (l, h, w),
+            Rotation::Wlh => (w, l, h),
+            Rotation::Whl => (w, h, l),
+            Rotation::Hlw => (h, l, w),
+            Rotation::Hwl => (h, w, l),
+        }
+    }
+}
+
+/// Where a parcel went: its index in the order, the corner nearest the
+/// crate's origin, and how it was turned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Placement {
+    pub parcel: usize,
+    pub position: (u32, u32, u32),
+    pub rotation: Rotation,
+    pub size: (u32, u32, u32),
+}
+
+impl Placement {
+    fn overlaps(&self, position: (u32, u32, u32), size: (u32, u32, u32)) -> bool {
+        let apart = |a: u32, da: u32, b: u32, db: u32| {
+            a as u64 + da as u64 <= b as u64 || b as u64 + db as u64 <= a as u64
+        };
+
+        !(apart(self.position.0, self.size.0, position.0, size.0)
+            || apart(self.position.1, self.size.1, position.1, size.1)
+            || apart(self.position.2, self.size.2, position.2, size.2))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Crate {
+    pub placements: Vec<Placement>,
+    points: Vec<(u32, u32, u32)>,
+}
+
+impl Crate {
+    fn new() -> Crate {
+        Crate { placements: Vec::new(), points: vec![(0, 0, 0)] }
+    }
+
+    pub fn volume(&self) -> u128 {
+        self.placements.iter().map(|p| p.size.0 as u128 * p.size.1 as u128 * p.size.2 as u128)
+            .sum()
+    }
+
+    /// Find room for a parcel of the given dimensions, turning it if needed.
+    fn find_room(&self, dims: (u32, u32, u32), container: (u32, u32, u32))
+        -> Option<((u32, u32, u32), Rotation)> {
+
+        for &point in &self.points {
+            for &rotation in &Rotation::ALL {
+                let size = rotation.apply(dims);
+                let fits = point.0 as u64 + size.0 as u64 <= container.0 as u64
+                    && point.1 as u64 + size.1 as u64 <= container.1 as u64
+                    && point.2 as u64 + size.2 as u64 <= container.2 as u64;
+
+                if fits && !self.placements.iter().any(|p| p.overlaps(point, size)) {
+                    return Some((point, rotation));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn place(&mut self, placement: Placement) {
+        let ((x, y, z), (dx, dy, dz)) = (placement.position, placement.size);
+
+        self.points.retain(|&p| p != placement.position);
+        self.points.extend([(x + dx, y, z), (x, y + dy, z), (x, y, z + dz)]);
+
+        // Floor first, then back to front, then left to right.
+        self.points.sort_unstable_by_key(|&(x, y, z)| (z, y, x));
+        self.points.dedup();
+        self.placements.push(placement);
+    }
+}
+
+/// Parcels packed into crates of one size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packing {
+    pub container: (u32, u32, u32),
+    pub crates: Vec<Crate>,
+    /// Parcels too big for a crate however they are turned.
+    pub unpacked: Vec<usize>,
+}
+
+impl Packing {
+    fn container_volume(&self) -> u128 {
+        let (l, w, h) = self.container;
+        l as u128 * w as u128 * h as u128
+    }
+
+    /// How full a crate is, from 0 to 1. A crate with no room in it, which
+    /// can only hold flat parcels, counts as empty.
+    pub fn fill_ratio(&self, n: usize) -> f64 {
+        if self.container_volume() == 0 {
+            return 0.0;
+        }
+
+        self.crates[n].volume() as f64 / self.container_volume() as f64
+    }
+
+    /// How full the crates are overall, from 0 to 1, or 0 if none were used
+    /// or they have no room in them.
+    pub fn total_fill_ratio(&self) -> f64 {
+        if self.crates.is_empty() || self.container_volume() == 0 {
+            return 0.0;
+        }
+
+        let packed: u128 = self.crates.iter().map(|c| c.volume()).sum();
+        packed as f64 / (self.container_volume() * self.crates.len() as u128) as f64
+    }
+}
+
+pub fn pack(parcels: &[Parcel], container: (u32, u32, u32)) -> Packing {
+    let mut order: Vec<usize> = (0..parcels.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(parcels[i].volume()));
+
+    let mut packing = Packing { container, crates: Vec::new(), unpacked: Vec::new() };
+
+    for i in order {
+        let dims = parcels[i].dimensions();
+        let found = packing.crates.iter().enumerate()
+            .find_map(|(n, c)| c.find_room(dims, container).map(|room| (n, room)));
+
+        let (n, (position, rotation)) = match found {
+            Some(f) => f,
+            None => {
+                let empty = Crate::new();
+
+                match empty.find_room(dims, container) {
+                    Some(room) => {
+                        packing.crates.push(empty);
+                        (packing.crates.len() - 1, room)
+                    },
+                    None => {
+                        packing.unpacked.push(i);
+                        continue;
+                    },
+                }
+            },
+        };
+
+        let size = rotation.apply(dims);
+        packing.crates[n].place(Placement { parcel: i, position, rotation, size });
+    }
+
+    packing.unpacked.sort_unstable();
+    packing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02;
+
+    #[test]
+    fn cubes_fill_a_crate() {
+        let packing = pack(&vec![Parcel::new(1, 1, 1); 8], (2, 2, 2));
+
+        assert_eq!(1, packing.crates.len());
+        assert_eq!(8, packing.crates[0].placements.len());
+        assert_eq!(1.0, packing.fill_ratio(0));
+    }
+
+    #[test]
+    fn turned_to_fit() {
+        let packing = pack(&[Parcel::new(4, 1, 2)], (1, 2, 4));
+        let placed = packing.crates[0].placements[0];
+
+        assert_eq!((1, 2, 4), placed.size);
+        assert_eq!(Rotation::Whl, placed.rotation);
+        assert_eq!((0, 0, 0), placed.position);
+    }
+
+    #[test]
+    fn biggest_first() {
+        let parcels = [Parcel::new(1, 1, 1), Parcel::new(2, 2, 2), Parcel::new(2, 2, 1)];
+        let packing = pack(&parcels, (2, 2, 3));
+
+        let order: Vec<usize> = packing.crates[0].placements.iter().map(|p| p.parcel).collect();
+        assert_eq!(vec![1, 2], order);
+        assert_eq!(2, packing.crates.len());
+        assert_eq!(12.0 / 24.0 + 1.0 / 24.0, packing.total_fill_ratio());
+    }
+
+    #[test]
+    fn too_big() {
+        let packing = pack(&[Parcel::new(5, 1, 1), Parcel::new(1, 1, 1)], (4, 4, 4));
+
+        assert_eq!(vec![0], packing.unpacked);
+        assert_eq!(1, packing.crates.len());
+        assert_eq!(0.0, pack(&[], (4, 4, 4)).total_fill_ratio());
+    }
+
+    #[test]
+    fn flat_crates() {
+        let packing = pack(&[Parcel::new(0, 0, 0), Parcel::new(2, 3, 0)], (0, 0, 0));
+
+        assert_eq!(vec![1], packing.unpacked);
+        assert_eq!(0.0, packing.fill_ratio(0));
+        assert_eq!(0.0, packing.total_fill_ratio());
+        assert_eq!(0.0, pack(&[Parcel::new(2, 3, 0)], (2, 3, 0)).total_fill_ratio());
+    }
+
+    #[test]
+    fn packings_are_sound() {
+        let orders = [
+            ("2x3x4\n1x1x10\n5x5x5\n3x3x3\n4x2x1\n6x1x1\n2x2x2", (6, 6, 6)),
+            ("1x1x10\n1x1x10\n10x1x1\n1x10x1\n2x2x2\n9x9x1", (10, 10, 2)),
+            ("3x3x3\n3x3x3\n3x3x3\n2x2x2\n2x2x2\n1x1x1\n1x1x1\n7x1x1", (4, 4, 6)),
+            ("5x4x3\n3x4x5\n4x5x3\n1x2x3\n2x2x2\n12x1x1", (5, 8, 3)),
+        ];
+
+        for (order, container) in orders {
+            let parcels = day02::parse_input(order).unwrap();
+            let packing = pack(&parcels, container);
+
+            let mut seen = vec![false; parcels.len()];
+
+            for c in &packing.crates {
+                for (n, p) in c.placements.iter().enumerate() {
+                    assert!(!seen[p.parcel]);
+                    seen[p.parcel] = true;
+                    assert_eq!(p.rotation.apply(parcels[p.parcel].dimensions()), p.size);
+
+                    assert!(p.position.0 + p.size.0 <= container.0);
+                    assert!(p.position.1 + p.size.1 <= container.1);
+                    assert!(p.position.2 + p.size.2 <= container.2);
+                    assert!(c.placements[..n].iter().all(|q| !q.overlaps(p.position, p.size)));
+                }
+            }
+
+            assert!(packing.unpacked.iter().all(|&i| !seen[i]));
+            assert_eq!(parcels.len(), seen.iter().filter(|&&s| s).count() + packing.unpacked.len());
+
+            let ratio = packing.total_fill_ratio();
+            assert!(ratio > 0.0 && ratio <= 1.0);
+        }
+    }
+}
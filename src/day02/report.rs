@@ -0,0 +1,222 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use crate::output::Format;
+use super::Parcel;
+
+const METRICS: [&str; 5] = ["area", "smallest_side", "paper", "ribbon", "volume"];
+
+/// Everything worked out for one parcel of an order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+    pub parcel: Parcel,
+    /// Surface area, smallest side, paper, ribbon and volume.
+    pub metrics: [u128; 5],
+    /// Whether the same box, however it is turned, came earlier in the order.
+    pub duplicate: bool,
+}
+
+/// A breakdown of an order, parcel by parcel, with statistics over all of
+/// them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub rows: Vec<Row>,
+}
+
+impl Report {
+    pub fn new(parcels: &[Parcel]) -> Report {
+        let mut seen = HashSet::new();
+
+        let rows = parcels.iter().map(|p| {
+            let (l, w, h) = p.dimensions();
+            let mut sorted = [l, w, h];
+            sorted.sort_unstable();
+
+            Row {
+                parcel: p.clone(),
+                metrics: [
+                    p.area(), p.smallest_side(), p.wrap_required(), p.ribbon_required(), p.volume(),
+                ],
+                duplicate: !seen.insert(sorted),
+            }
+        }).collect();
+
+        Report { rows }
+    }
+
+    pub fn duplicates(&self) -> usize {
+        self.rows.iter().filter(|r| r.duplicate).count()
+    }
+
+    pub fn totals(&self) -> [u128; 5] {
+        self.fold(0, |a, b| a + b)
+    }
+
+    /// The means, or zero for an empty order.
+    pub fn means(&self) -> [f64; 5] {
+        let n = self.rows.len().max(1) as f64;
+        self.totals().map(|t| t as f64 / n)
+    }
+
+    pub fn largest(&self) -> [u128; 5] {
+        self.fold(0, u128::max)
+    }
+
+    fn fold<F: Fn(u128, u128) -> u128>(&self, init: u128, f: F) -> [u128; 5] {
+        let mut out = [init; 5];
+
+        for row in &self.rows {
+            for (o, &m) in out.iter_mut().zip(&row.metrics) {
+                *o = f(*o, m);
+            }
+        }
+
+        out
+    }
+
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.table(),
+            Format::Json => self.json(),
+            Format::Csv => self.csv(),
+        }
+    }
+
+    fn table(&self) -> String {
+        let mut out = format!(
+            "{:>7}  {:<14}  {:>12}  {:>13}  {:>12}  {:>12}  {:>12}\n",
+            "Parcel", "Dimensions", "Area", "Smallest side", "Paper", "Ribbon", "Volume"
+        );
+
+        let mut line = |label: &str, dims: &str, values: Vec<String>| {
+            writeln!(
+                out, "{:>7}  {:<14}  {:>12}  {:>13}  {:>12}  {:>12}  {:>12}",
+                label, dims, values[0], values[1], values[2], values[3], values[4]
+            ).unwrap();
+        };
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let values = row.metrics.iter().map(|m| m.to_string()).collect();
+            line(&(i + 1).to_string(), &row.parcel.to_string(), values);
+        }
+
+        line("Total", "", self.totals().iter().map(|t| t.to_string()).collect());
+        line("Mean", "", self.means().iter().map(|m| format!("{:.2}", m)).collect());
+        line("Largest", "", self.largest().iter().map(|l| l.to_string()).collect());
+
+        writeln!(out, "\nDuplicates: {}", self.duplicates()).unwrap();
+        out
+    }
+
+    // Summary rows follow the parcels, with no dimensions. The duplicate
+    // column holds the number of duplicates on the total row.
+    fn csv(&self) -> String {
+        let mut out = format!("parcel,length,width,height,{},duplicate\n", METRICS.join(","));
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let (l, w, h) = row.parcel.dimensions();
+            let metrics: Vec<String> = row.metrics.iter().map(|m| m.to_string()).collect();
+            writeln!(out, "{},{},{},{},{},{}", i + 1, l, w, h, metrics.join(","), row.duplicate)
+                .unwrap();
+        }
+
+        let join = |values: Vec<String>| values.join(",");
+        writeln!(
+            out, "total,,,,{},{}",
+            join(self.totals().iter().map(|t| t.to_string()).collect()), self.duplicates()
+        ).unwrap();
+        writeln!(
+            out, "mean,,,,{},", join(self.means().iter().map(|m| format!("{:.2}", m)).collect())
+        ).unwrap();
+        writeln!(
+            out, "largest,,,,{},", join(self.largest().iter().map(|l| l.to_string()).collect())
+        ).unwrap();
+
+        out
+    }
+
+    fn json(&self) -> String {
+        let object = |values: Vec<String>| {
+            let fields: Vec<String> = METRICS.iter().zip(values)
+                .map(|(name, v)| format!("\"{}\":{}", name, v))
+                .collect();
+            fields.join(",")
+        };
+
+        let parcels: Vec<String> = self.rows.iter().enumerate().map(|(i, row)| {
+            let (l, w, h) = row.parcel.dimensions();
+            format!(
+                "{{\"parcel\":{},\"length\":{},\"width\":{},\"height\":{},{},\"duplicate\":{}}}",
+                i + 1, l, w, h, object(row.metrics.iter().map(|m| m.to_string()).collect()),
+                row.duplicate
+            )
+        }).collect();
+
+        format!(
+            "{{\"parcels\":[{}],\"total\":{{{}}},\"mean\":{{{}}},\"largest\":{{{}}},\
+             \"duplicates\":{}}}\n",
+            parcels.join(","),
+            object(self.totals().iter().map(|t| t.to_string()).collect()),
+            object(self.means().iter().map(|m| format!("{:.2}", m)).collect()),
+            object(self.largest().iter().map(|l| l.to_string()).collect()),
+            self.duplicates()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report::new(&[Parcel::new(2, 3, 4), Parcel::new(1, 1, 10), Parcel::new(4, 2, 3)])
+    }
+
+    #[test]
+    fn statistics() {
+        let r = report();
+
+        assert_eq!([52, 6, 58, 34, 24], r.rows[0].metrics);
+        assert_eq!([42, 1, 43, 14, 10], r.rows[1].metrics);
+        let duplicates: Vec<bool> = r.rows.iter().map(|row| row.duplicate).collect();
+        assert_eq!(vec![false, false, true], duplicates);
+
+        assert_eq!([146, 13, 159, 82, 58], r.totals());
+        assert_eq!([52, 6, 58, 34, 24], r.largest());
+        assert_eq!(58.0 / 3.0, r.means()[4]);
+        assert_eq!(1, r.duplicates());
+
+        assert_eq!([0.0; 5], Report::new(&[]).means());
+    }
+
+    #[test]
+    fn formats() {
+        let r = report();
+
+        let table = r.format(Format::Text);
+        assert!(table.contains("      2  1x1x10                    42"));
+        assert!(table.ends_with("\nDuplicates: 1\n"));
+
+        let csv = r.format(Format::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            "parcel,length,width,height,area,smallest_side,paper,ribbon,volume,duplicate", lines[0]
+        );
+        assert_eq!("3,4,2,3,52,6,58,34,24,true", lines[3]);
+        assert_eq!("total,,,,146,13,159,82,58,1", lines[4]);
+        assert_eq!("mean,,,,48.67,4.33,53.00,27.33,19.33,", lines[5]);
+
+        let json = r.format(Format::Json);
+        assert!(json.starts_with(
+            "{\"parcels\":[{\"parcel\":1,\"length\":2,\"width\":3,\"height\":4,\"area\":52,"
+        ));
+        assert!(json.ends_with("\"volume\":24},\"duplicates\":1}\n"));
+    }
+}
@@ -0,0 +1,161 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use super::Parcel;
+
+/// A rule for how much of something a parcel needs to wrap it. The
+/// puzzle's rules are `Paper` and `Ribbon`; others can be added for the
+/// same parcels, and totalled with `day02::total`.
+pub trait WrappingPolicy {
+    /// What is being measured, for error messages.
+    fn material(&self) -> &str;
+
+    /// The amount needed, or `None` if it is too big for a u128.
+    fn amount(&self, parcel: &Parcel) -> Option<u128>;
+}
+
+/// Enough paper to cover the parcel, plus the area of its smallest side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Paper;
+
+impl WrappingPolicy for Paper {
+    fn material(&self) -> &str {
+        "wrapping paper"
+    }
+
+    fn amount(&self, parcel: &Parcel) -> Option<u128> {
+        Some(parcel.wrap_required())
+    }
+}
+
+/// Enough ribbon to go round the smallest perimeter, plus a bow as long as
+/// the parcel's volume.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ribbon;
+
+impl WrappingPolicy for Ribbon {
+    fn material(&self) -> &str {
+        "ribbon"
+    }
+
+    fn amount(&self, parcel: &Parcel) -> Option<u128> {
+        Some(parcel.ribbon_required())
+    }
+}
+
+/// Paper with room to overlap: every dimension is taken to be `margin`
+/// bigger than it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverlapMargin(pub u32);
+
+impl WrappingPolicy for OverlapMargin {
+    fn material(&self) -> &str {
+        "wrapping paper"
+    }
+
+    fn amount(&self, parcel: &Parcel) -> Option<u128> {
+        let (l, w, h) = parcel.dimensions();
+        let m = self.0 as u128;
+        let (l, w, h) = (l as u128 + m, w as u128 + m, h as u128 + m);
+        let sides = [l * w, w * h, h * l];
+
+        Some(2 * sides.iter().sum::<u128>() + sides.iter().min().expect("There are three sides."))
+    }
+}
+
+/// Ribbon with a bow of `numerator / denominator` times the parcel's
+/// volume, rounded up, instead of the whole volume.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScaledBow {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl WrappingPolicy for ScaledBow {
+    fn material(&self) -> &str {
+        "ribbon"
+    }
+
+    fn amount(&self, parcel: &Parcel) -> Option<u128> {
+        let bow = parcel.volume().checked_mul(self.numerator as u128)?;
+        parcel.shortest_perimeter().checked_add(bow.div_ceil(self.denominator.max(1) as u128))
+    }
+}
+
+/// Twice what another policy needs, for fragile parcels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DoubleWrap<P>(pub P);
+
+impl<P: WrappingPolicy> WrappingPolicy for DoubleWrap<P> {
+    fn material(&self) -> &str {
+        self.0.material()
+    }
+
+    fn amount(&self, parcel: &Parcel) -> Option<u128> {
+        self.0.amount(parcel)?.checked_mul(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02;
+
+    #[test]
+    fn puzzle_rules() {
+        let p = Parcel::new(2, 3, 4);
+
+        assert_eq!(Some(58), Paper.amount(&p));
+        assert_eq!(Some(34), Ribbon.amount(&p));
+    }
+
+    #[test]
+    fn own_rules() {
+        let p = Parcel::new(2, 3, 4);
+
+        assert_eq!(Paper.amount(&p), OverlapMargin(0).amount(&p));
+        assert_eq!(Paper.amount(&Parcel::new(3, 4, 5)), OverlapMargin(1).amount(&p));
+
+        assert_eq!(Ribbon.amount(&p), ScaledBow { numerator: 1, denominator: 1 }.amount(&p));
+        assert_eq!(Some(10 + 5), ScaledBow { numerator: 1, denominator: 5 }.amount(&p));
+
+        assert_eq!(Some(116), DoubleWrap(Paper).amount(&p));
+        assert_eq!(Some(68), DoubleWrap(Ribbon).amount(&p));
+    }
+
+    #[test]
+    fn totals_for_any_policy() {
+        let parcels = vec![Parcel::new(2, 3, 4), Parcel::new(1, 1, 10)];
+        let policies: Vec<Box<dyn WrappingPolicy>> = vec![
+            Box::new(Paper), Box::new(DoubleWrap(Paper)), Box::new(OverlapMargin(1)),
+        ];
+
+        let totals: Vec<u64> = policies.iter()
+            .map(|p| day02::total(&parcels, p.as_ref()).unwrap())
+            .collect();
+
+        assert_eq!(vec![101, 202, 106 + 100], totals);
+        assert_eq!(day02::part2(&parcels).unwrap(), day02::total(&parcels, &Ribbon).unwrap());
+
+        let huge = vec![Parcel::new(u32::MAX, u32::MAX, 1)];
+        assert_eq!(
+            "day 2: too much wrapping paper to count",
+            day02::total(&huge, &DoubleWrap(Paper)).unwrap_err().to_string()
+        );
+
+        // The bow only just fits in a u128, so doubling it is too much.
+        let cube = vec![Parcel::new(u32::MAX, u32::MAX, u32::MAX)];
+        let bow = ScaledBow { numerator: u32::MAX, denominator: 1 };
+        assert!(bow.amount(&cube[0]).is_some());
+        assert_eq!(None, DoubleWrap(bow).amount(&cube[0]));
+        assert_eq!(
+            "day 2: too much ribbon to count",
+            day02::total(&cube, &DoubleWrap(bow)).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::HashSet;
+use crate::{Error, ParseError, Result, Solution};
+
+pub struct SphericalHouses;
+
+impl Solution for SphericalHouses {
+    const DAY: u8 = 3;
+    const NAME: &'static str = "spherical_houses";
+
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<&str> {
+        check_directions(input)?;
+        Ok(input)
+    }
+
+    fn part1(directions: &&str) -> usize {
+        houses_visited(directions)
+    }
+
+    fn part2(directions: &&str) -> usize {
+        houses_visited_with_robot(directions)
+    }
+}
+
+pub fn part1(directions: &str) -> Result<usize> {
+    check_directions(directions)?;
+    Ok(houses_visited(directions))
+}
+
+pub fn part2(directions: &str) -> Result<usize> {
+    check_directions(directions)?;
+    Ok(houses_visited_with_robot(directions))
+}
+
+fn houses_visited(directions: &str) -> usize {
+    visit_houses(directions).expect(CHECKED).len()
+}
+
+fn houses_visited_with_robot(directions: &str) -> usize {
+    let (evens, odds) = divide_directions(directions);
+    let santa = visit_houses(evens.as_str()).expect(CHECKED);
+    let robo = visit_houses(odds.as_str()).expect(CHECKED);
+
+    santa.union(&robo).count()
+}
+
+const CHECKED: &str = "Directions are checked when parsed.";
+
+/// Find every character that is not a direction. The directions should be
+/// on a single line, so line breaks are reported too.
+pub fn check_directions(directions: &str) -> Result<()> {
+    let mut errors = Vec::new();
+    let mut line = 1;
+    let mut column = 0;
+
+    for dir in directions.chars() {
+        column += 1;
+
+        if !"^v<>".contains(dir) {
+            let e = ParseError::new(column, &dir.to_string(), "unexpected direction");
+            errors.push(e.at(SphericalHouses::DAY, line));
+        }
+
+        if dir == '\n' {
+            line += 1;
+            column = 0;
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+pub fn visit_houses(directions: &str) -> std::result::Result<HashSet<(i32, i32)>, ParseError> {
+    let mut x: i32 = 0;
+    let mut y: i32 = 0;
+    let mut visits = HashSet::new();
+
+    // We visit the first house immediately.
+    visits.insert((x, y));
+
+    for (i, dir) in directions.chars().enumerate() {
+        match dir {
+            '^' => y += 1,
+            'v' => y -= 1,
+            '<' => x -= 1,
+            '>' => x += 1,
+            _ => return Err(ParseError::new(i + 1, &dir.to_string(), "unexpected direction")),
+        }
+
+        // For each visit add to the set, which will ignore repeat visits.
+        visits.insert((x, y));
+    }
+
+    Ok(visits)
+}
+
+pub fn divide_directions(directions: &str) -> (String, String) {
+    let mut s1 = String::new();
+    let mut s2 = String::new();
+
+    for (i, d) in directions.char_indices() {
+        if i % 2 == 0 {
+            s1.push(d);
+        } else {
+            s2.push(d);
+        }
+    }
+
+    (s1, s2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_visits() {
+        let visits = visit_houses(">").unwrap();
+        assert_eq!(2, visits.len());
+
+        let visits = visit_houses("^>v<").unwrap();
+        assert_eq!(4, visits.len());
+    }
+
+    #[test]
+    fn bad_directions() {
+        assert_eq!(
+            Err(ParseError::new(5, "a", "unexpected direction")), visit_houses("<>^va")
+        );
+
+        match check_directions("<>^va\n^x") {
+            Err(Error::Parse(errors)) => {
+                assert_eq!((1, 5), (errors[0].line, errors[0].column));
+                assert_eq!((1, 6), (errors[1].line, errors[1].column));
+                assert_eq!((2, 2), (errors[2].line, errors[2].column));
+            },
+            _ => panic!("Expected parse errors."),
+        }
+    }
+
+    #[test]
+    fn unchecked_directions() {
+        assert_eq!(4, part1("^>v<").unwrap());
+        assert_eq!(3, part2("^v").unwrap());
+        assert!(matches!(part1("^>x"), Err(Error::Parse(_))));
+        assert!(matches!(part2("^\nv"), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn split_dirs() {
+        let (one, two) = divide_directions("^v^v^v^v");
+        assert_eq!("^^^^", one);
+        assert_eq!("vvvv", two);
+    }
+}
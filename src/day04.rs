@@ -0,0 +1,78 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use crate::{Error, ParseError, Result, Solution};
+
+pub struct StockingStuffer;
+
+impl Solution for StockingStuffer {
+    const DAY: u8 = 4;
+    const NAME: &'static str = "stocking_stuffer";
+
+    type Input<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<&str> {
+        let key = input.trim();
+
+        match key.chars().position(char::is_whitespace) {
+            _ if key.is_empty() => Err(Error::invalid(Self::DAY, "the secret key is empty")),
+            Some(i) => {
+                let e = ParseError::new(i + 1, key, "the secret key contains whitespace");
+                Err(e.at(Self::DAY, 1).into())
+            },
+            None => Ok(key),
+        }
+    }
+
+    fn part1(key: &&str) -> u32 {
+        find_suffix(key, 5, 0)
+    }
+
+    fn part2(key: &&str) -> u32 {
+        find_suffix(key, 6, 0)
+    }
+}
+
+pub fn find_suffix(key: &str, digits: usize, start: u32) -> u32 {
+    let mut i = start;
+    let test = format!("{:0<1$}", "", digits);
+
+    loop {
+        let digest = md5::compute(key.to_string() + &i.to_string());
+
+        if format!("{:x}", digest)[0..digits] == test {
+            return i;
+        }
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_key() {
+        assert_eq!("abcdef", StockingStuffer::parse("abcdef\n").unwrap());
+        assert!(StockingStuffer::parse("  ").is_err());
+        assert!(StockingStuffer::parse("abc def").is_err());
+    }
+
+    #[test]
+    fn get_suffix() {
+        assert_eq!(609_043, find_suffix("abcdef", 5, 600_000));
+    }
+
+    #[test]
+    fn search_from_zero() {
+        assert_eq!(12_181, StockingStuffer::part1(&"a"));
+    }
+}
@@ -6,24 +6,35 @@
 // Public Domain
 //
 
-#[macro_use]
-extern crate lazy_static;
-
-use std::fs;
 use fancy_regex::Regex;
+use crate::{Result, Solution};
 
 const NAUGHTY: &[&str] = &["ab", "cd", "pq", "xy"];
 
-fn main() {
-    let input = fs::read_to_string("./etc/intern_elves.txt")
-        .expect("Something went wrong reading the file.");
-    let input = input.trim();
+pub struct InternElves;
+
+impl Solution for InternElves {
+    const DAY: u8 = 5;
+    const NAME: &'static str = "intern_elves";
+
+    type Input<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(strings: &&str) -> u32 {
+        part1(strings)
+    }
 
-    println!("Part 1: {}", part1(input));
-    println!("Part 2: {}", part2(input));
+    fn part2(strings: &&str) -> u32 {
+        part2(strings)
+    }
 }
 
-fn part1(strings: &str) -> u32 {
+pub fn part1(strings: &str) -> u32 {
     let mut num = 0;
 
     for line in strings.lines() {
@@ -35,7 +46,7 @@ fn part1(strings: &str) -> u32 {
     num
 }
 
-fn part2(strings: &str) -> u32 {
+pub fn part2(strings: &str) -> u32 {
     let mut num = 0;
 
     for line in strings.lines() {
@@ -47,7 +58,7 @@ fn part2(strings: &str) -> u32 {
     num
 }
 
-fn is_nice_string1(text: &str) -> bool {
+pub fn is_nice_string1(text: &str) -> bool {
     lazy_static! {
         static ref VOWELS: Regex = Regex::new("[aeiou].*[aeiou].*[aeiou]").unwrap();
         static ref DOUBLES: Regex = Regex::new("(.)\\1{1,}").unwrap();
@@ -72,7 +83,7 @@ fn is_nice_string1(text: &str) -> bool {
     true
 }
 
-fn is_nice_string2(text: &str) -> bool {
+pub fn is_nice_string2(text: &str) -> bool {
     lazy_static! {
         static ref DOUBLE: Regex = Regex::new("(..).*\\1").unwrap();
         static ref BETWEEN: Regex = Regex::new("(.).\\1").unwrap();
@@ -99,16 +110,16 @@ mod tests {
     fn nice_strings1() {
         assert!(is_nice_string1("ugknbfddgicrmopn"));
         assert!(is_nice_string1("aaa"));
-        assert!(is_nice_string1("haegwjzuvuyypxyu") == false); // Contains 'xy'.
-        assert!(is_nice_string1("dvszwmarrgswjxmb") == false); // Only one vowel.
-        assert!(is_nice_string1("jchzalrnumimnmhp") == false); // No double letter.
+        assert!(!is_nice_string1("haegwjzuvuyypxyu")); // Contains 'xy'.
+        assert!(!is_nice_string1("dvszwmarrgswjxmb")); // Only one vowel.
+        assert!(!is_nice_string1("jchzalrnumimnmhp")); // No double letter.
     }
 
     #[test]
     fn nice_strings2() {
         assert!(is_nice_string2("qjhvhtzxzqqjkmpb"));
         assert!(is_nice_string2("xxyxx"));
-        assert!(is_nice_string2("uurcxstgmygtbstg") == false); // No repeat with single letter.
-        assert!(is_nice_string2("ieodomkazucvgmuy") == false); // No pair appearing twice.
+        assert!(!is_nice_string2("uurcxstgmygtbstg")); // No repeat with single letter.
+        assert!(!is_nice_string2("ieodomkazucvgmuy")); // No pair appearing twice.
     }
 }
@@ -0,0 +1,373 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::fmt;
+use fancy_regex::Regex;
+use crate::{ParseError, Result, Solution};
+use crate::error;
+
+pub struct FireHazard;
+
+impl Solution for FireHazard {
+    const DAY: u8 = 6;
+    const NAME: &'static str = "fire_hazard";
+
+    type Input<'a> = Vec<Command>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        error::parse_lines(Self::DAY, input, LightingGrid::parse_command)
+    }
+
+    fn part1(commands: &Vec<Command>) -> u64 {
+        run(1, commands)
+    }
+
+    fn part2(commands: &Vec<Command>) -> u64 {
+        run(2, commands)
+    }
+}
+
+pub fn run(part: u8, commands: &[Command]) -> u64 {
+    let mut lights = LightingGrid::new(part);
+
+    for command in commands {
+        lights.apply(command);
+    }
+
+    lights.brightness()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::TurnOn => write!(f, "turn on"),
+            Action::TurnOff => write!(f, "turn off"),
+            Action::Toggle => write!(f, "toggle"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Command {
+    pub action: Action,
+    pub lx: usize,
+    pub ly: usize,
+    pub hx: usize,
+    pub hy: usize,
+}
+
+impl Command {
+    /// The number of lights this command affects.
+    pub fn area(&self) -> u64 {
+        let width = (self.hx + 1).saturating_sub(self.lx);
+        let height = (self.hy + 1).saturating_sub(self.ly);
+
+        (width * height) as u64
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "{} {},{} through {},{}", self.action, self.lx, self.ly, self.hx, self.hy
+        )
+    }
+}
+
+pub struct LightingGrid {
+    version: u8,
+    // Boxed as it is too big for the stack.
+    grid: Box<[[u32; LightingGrid::SIDE_LENGTH]]>,
+}
+
+impl LightingGrid {
+    const SIDE_LENGTH: usize = 1000;
+    const CMD_REGEX: &'static str = r"^(turn on|turn off|toggle) (\d+),(\d+) through (\d+),(\d+)$";
+
+    pub fn new(v: u8) -> LightingGrid {
+        LightingGrid {
+            version: v,
+            grid: vec![[0; LightingGrid::SIDE_LENGTH]; LightingGrid::SIDE_LENGTH].into_boxed_slice(),
+        }
+    }
+
+    pub fn run_command(&mut self, cmd: &str) -> std::result::Result<(), ParseError> {
+        let command = LightingGrid::parse_command(cmd)?;
+        self.apply(&command);
+
+        Ok(())
+    }
+
+    pub fn apply(&mut self, command: &Command) {
+        let Command { action, lx, ly, hx, hy } = *command;
+
+        if self.version == 1 {
+            match action {
+                Action::TurnOn => self.turn_on(lx, ly, hx, hy),
+                Action::TurnOff => self.turn_off(lx, ly, hx, hy),
+                Action::Toggle => self.toggle(lx, ly, hx, hy),
+            }
+        } else {
+            match action {
+                Action::TurnOn => self.adjust_brightness(1, lx, ly, hx, hy),
+                Action::TurnOff => self.adjust_brightness(-1, lx, ly, hx, hy),
+                Action::Toggle => self.adjust_brightness(2, lx, ly, hx, hy),
+            }
+        }
+    }
+
+    pub fn adjust_brightness(&mut self, value: i8, lx: usize, ly: usize, hx: usize, hy: usize) {
+        for x in lx..=hx {
+            for y in ly..=hy {
+                let current = self.grid[x][y];
+                self.grid[x][y] = if value < 0 {
+                    current.saturating_sub(value.unsigned_abs() as u32)
+                } else {
+                    current + value as u32
+                };
+            }
+        }
+    }
+
+    pub fn turn_on(&mut self, lx: usize, ly: usize, hx: usize, hy: usize) {
+        for x in lx..=hx {
+            for y in ly..=hy {
+                self.grid[x][y] = 1;
+            }
+        }
+    }
+
+    pub fn turn_off(&mut self, lx: usize, ly: usize, hx: usize, hy: usize) {
+        for x in lx..=hx {
+            for y in ly..=hy {
+                self.grid[x][y] = 0;
+            }
+        }
+    }
+
+    pub fn toggle(&mut self, lx: usize, ly: usize, hx: usize, hy: usize) {
+        for x in lx..=hx {
+            for y in ly..=hy {
+                self.grid[x][y] = 1 - self.grid[x][y];
+            }
+        }
+    }
+
+    pub fn brightness(&self) -> u64 {
+        self.grid.iter().flat_map(|r| r.iter().map(|&c| c as u64)).sum()
+    }
+
+    pub fn parse_command(cmd: &str) -> std::result::Result<Command, ParseError> {
+        lazy_static! {
+            static ref CMD: Regex = Regex::new(LightingGrid::CMD_REGEX).unwrap();
+        }
+
+        let tokens = match CMD.captures(cmd) {
+            Ok(Some(t)) => t,
+            _ => return Err(ParseError::new(1, cmd, "invalid command")),
+        };
+
+        let coordinate = |i| {
+            let group = tokens.get(i).expect("Group missing.");
+
+            match group.as_str().parse::<usize>() {
+                Ok(c) if c < LightingGrid::SIDE_LENGTH => Ok(c),
+                _ => {
+                    let column = cmd[..group.start()].chars().count() + 1;
+                    Err(ParseError::new(column, group.as_str(), "coordinate out of range"))
+                },
+            }
+        };
+
+        let action = match tokens.get(1).expect("Group missing.").as_str() {
+            "turn on" => Action::TurnOn,
+            "turn off" => Action::TurnOff,
+            _ => Action::Toggle,
+        };
+
+        Ok(Command {
+            action,
+            lx: coordinate(2)?,
+            ly: coordinate(3)?,
+            hx: coordinate(4)?,
+            hy: coordinate(5)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_grid_v1() {
+        let lights = LightingGrid::new(1);
+        assert_eq!(0, lights.grid[0][0]);
+        assert_eq!(0, lights.grid[999][999]);
+        assert_eq!(0, lights.brightness());
+    }
+
+    #[test]
+    fn new_grid_v2() {
+        let lights = LightingGrid::new(2);
+        assert_eq!(0, lights.grid[0][0]);
+        assert_eq!(0, lights.grid[999][999]);
+        assert_eq!(0, lights.brightness());
+    }
+
+    #[test]
+    fn lights_brightness() {
+        let mut lights = LightingGrid::new(2);
+
+        lights.adjust_brightness(1, 0, 0, 0, 0);
+        assert_eq!(1, lights.grid[0][0]);
+        assert_eq!(1, lights.brightness());
+
+        lights.adjust_brightness(-1, 0, 0, 10, 10);
+        assert_eq!(0, lights.grid[0][0]);
+        assert_eq!(0, lights.grid[10][10]);
+        assert_eq!(0, lights.brightness());
+
+        lights.adjust_brightness(2, 0, 0, 999, 999);
+        assert_eq!(2, lights.grid[0][0]);
+        assert_eq!(2, lights.grid[999][999]);
+        assert_eq!(2_000_000, lights.brightness());
+    }
+
+    #[test]
+    fn lights_on_off() {
+        let mut lights = LightingGrid::new(1);
+
+        lights.turn_on(0, 0, 999, 999);
+        assert_eq!(1, lights.grid[0][0]);
+        assert_eq!(1, lights.grid[999][999]);
+        assert_eq!(1_000_000, lights.brightness());
+
+        lights.turn_off(500, 0, 999, 999);
+        assert_eq!(1, lights.grid[0][0]);
+        assert_eq!(1, lights.grid[499][0]);
+        assert_eq!(0, lights.grid[500][0]);
+        assert_eq!(0, lights.grid[999][999]);
+        assert_eq!(500_000, lights.brightness());
+    }
+
+    #[test]
+    fn lights_toggle() {
+        let mut lights = LightingGrid::new(1);
+
+        lights.toggle(500, 0, 999, 999);
+        assert_eq!(0, lights.grid[0][0]);
+        assert_eq!(0, lights.grid[499][0]);
+        assert_eq!(1, lights.grid[500][0]);
+        assert_eq!(1, lights.grid[999][999]);
+
+        lights.toggle(0, 0, 999, 999);
+        assert_eq!(1, lights.grid[0][0]);
+        assert_eq!(1, lights.grid[499][0]);
+        assert_eq!(0, lights.grid[500][0]);
+        assert_eq!(0, lights.grid[999][999]);
+    }
+
+    #[test]
+    fn parse_cmd() {
+        let cmd = LightingGrid::parse_command("toggle 0,0 through 999,999").unwrap();
+        assert_eq!(Action::Toggle, cmd.action);
+        assert_eq!(0, cmd.lx);
+        assert_eq!(0, cmd.ly);
+        assert_eq!(999, cmd.hx);
+        assert_eq!(999, cmd.hy);
+
+        let cmd = LightingGrid::parse_command("turn on 5,10 through 600,500").unwrap();
+        assert_eq!(Action::TurnOn, cmd.action);
+        assert_eq!(5, cmd.lx);
+        assert_eq!(10, cmd.ly);
+        assert_eq!(600, cmd.hx);
+        assert_eq!(500, cmd.hy);
+
+        let cmd = LightingGrid::parse_command("turn off 20,500 through 21,501").unwrap();
+        assert_eq!(Action::TurnOff, cmd.action);
+        assert_eq!(20, cmd.lx);
+        assert_eq!(500, cmd.ly);
+        assert_eq!(21, cmd.hx);
+        assert_eq!(501, cmd.hy);
+    }
+
+    #[test]
+    fn brightness_above_255() {
+        let mut lights = LightingGrid::new(2);
+
+        for _ in 0..200 {
+            lights.run_command("toggle 0,0 through 1,1").unwrap();
+        }
+        assert_eq!(400, lights.grid[1][1]);
+        assert_eq!(1_600, lights.brightness());
+    }
+
+    #[test]
+    fn print_cmd() {
+        let cmd = "turn off 20,500 through 21,501";
+        assert_eq!(cmd, LightingGrid::parse_command(cmd).unwrap().to_string());
+        assert_eq!(4, LightingGrid::parse_command(cmd).unwrap().area());
+    }
+
+    #[test]
+    fn parse_bad_cmd() {
+        assert_eq!(
+            Err(ParseError::new(1, "switch 0,0 through 1,1", "invalid command")),
+            LightingGrid::parse_command("switch 0,0 through 1,1")
+        );
+        assert_eq!(
+            Err(ParseError::new(20, "1000", "coordinate out of range")),
+            LightingGrid::parse_command("toggle 0,0 through 1000,5")
+        );
+        assert!(LightingGrid::parse_command("toggle 0,0 through 99999999999999999999,5").is_err());
+        assert!(FireHazard::parse("toggle 0,0 through 1,1\n\ntoggle 1,1").is_err());
+    }
+
+    #[test]
+    fn run_v1() {
+        let mut lights = LightingGrid::new(1);
+        assert_eq!(0, lights.brightness());
+
+        lights.run_command("turn on 0,0 through 999,999").unwrap();
+        assert_eq!(1_000_000, lights.brightness());
+
+        lights.run_command("toggle 0,0 through 999,0").unwrap();
+        assert_eq!(999_000, lights.brightness());
+
+        lights.run_command("turn off 499,499 through 500,500").unwrap();
+        assert_eq!(998_996, lights.brightness());
+    }
+
+    #[test]
+    fn run_v2() {
+        let mut lights = LightingGrid::new(2);
+        assert_eq!(0, lights.brightness());
+
+        lights.run_command("turn on 0,0 through 999,999").unwrap();
+        assert_eq!(1_000_000, lights.brightness());
+
+        lights.run_command("toggle 0,0 through 999,0").unwrap();
+        assert_eq!(1_002_000, lights.brightness());
+
+        lights.run_command("turn off 499,499 through 500,500").unwrap();
+        assert_eq!(1_001_996, lights.brightness());
+
+        lights.run_command("turn off 499,499 through 500,500").unwrap();
+        assert_eq!(1_001_996, lights.brightness());
+    }
+}
@@ -0,0 +1,438 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use fancy_regex::Regex;
+use crate::{Error, ParseError, Result, Solution};
+use crate::error;
+
+const INST_REGEX: &str = r"^(.+) -> ([a-z]+)$";
+
+pub struct AssemblyRequired;
+
+impl Solution for AssemblyRequired {
+    const DAY: u8 = 7;
+    const NAME: &'static str = "assembly_required";
+
+    type Input<'a> = HashMap<&'a str, Gate<'a>>;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(input: &str) -> Result<HashMap<&str, Gate<'_>>> {
+        let instructions = parse_instructions(input)?;
+
+        if !instructions.contains_key("a") {
+            return Err(Error::invalid(Self::DAY, "no instruction drives wire 'a'"));
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &HashMap<&str, Gate>) -> u16 {
+        get_signal("a", instructions)
+    }
+
+    fn part2(instructions: &HashMap<&str, Gate>) -> u16 {
+        let a = get_signal("a", instructions);
+        let mut instructions = instructions.clone();
+
+        instructions.insert("b", Gate::None(Parameter::Value(a)));
+        get_signal("a", &instructions)
+    }
+}
+
+pub fn get_signal(wire: &str, instructions: &HashMap<&str, Gate>) -> u16 {
+    fn run_instructions<'s>(wire: &'s str, insts: &'s HashMap<&str, Gate>,
+                        memo: &mut HashMap<&'s str, u16>) -> u16 {
+
+        if memo.contains_key(wire) {
+            return *memo.get(wire).unwrap();
+        }
+
+        match insts.get(wire) {
+            None => panic!("No wire {}!", wire),
+            Some(gate) => {
+                match gate {
+                    Gate::None(p) => match p {
+                        Parameter::Label(l) => {
+                            let r = run_instructions(l, insts, memo);
+                            memo.insert(l, r);
+                            r
+                        },
+                        Parameter::Value(v) => {
+                            memo.insert(wire, *v);
+                            *v
+                        },
+                    },
+
+                    Gate::Not(np) => {
+                        let r = run_instructions(np, insts, memo);
+                        memo.insert(np, r);
+                        !r
+                    },
+
+                    Gate::Or(o1, o2) => {
+                        let r = run_instructions(o1, insts, memo);
+                        let s = run_instructions(o2, insts, memo);
+                        memo.insert(o1, r);
+                        memo.insert(o2, s);
+                        r | s
+                    },
+
+                    Gate::And(a1, a2) => match a1 {
+                        Parameter::Label(la) => {
+                            let r = run_instructions(la, insts, memo);
+                            let s = run_instructions(a2, insts, memo);
+                            memo.insert(la, r);
+                            memo.insert(a2, s);
+                            r & s
+                        },
+                        Parameter::Value(va) => {
+                            let r = run_instructions(a2, insts, memo);
+                            memo.insert(a2, r);
+                            *va & r
+                        }
+                    },
+
+                    Gate::Lshift(l1, l2) => {
+                        let r = run_instructions(l1, insts, memo);
+                        memo.insert(l1, r);
+                        r << l2
+                    },
+
+                    Gate::Rshift(r1, r2) => {
+                        let r = run_instructions(r1, insts, memo);
+                        memo.insert(r1, r);
+                        r >> r2
+                    },
+                }
+            },
+        }
+    }
+
+    let mut cache: HashMap<&str, u16> = HashMap::new();
+    run_instructions(wire, instructions, &mut cache)
+}
+
+pub fn parse_instructions(input: &str) -> Result<HashMap<&str, Gate<'_>>> {
+    let parsed = error::parse_lines(AssemblyRequired::DAY, input, parse_line)?;
+    let lines: Vec<&str> = input.lines().collect();
+    let mut errors = Vec::new();
+    let mut sinks = HashMap::new();
+
+    for (i, (sink, _)) in parsed.iter().enumerate() {
+        sinks.insert(*sink, i);
+    }
+
+    // Every wire used as an input must be driven by something.
+    for (i, (_, gate)) in parsed.iter().enumerate() {
+        for wire in gate.inputs() {
+            if !sinks.contains_key(wire) {
+                let column = columns(lines[i]).find(|&(_, t)| t == wire).map_or(1, |(c, _)| c);
+                let e = ParseError::new(column, wire, "no instruction drives this wire");
+                errors.push(e.at(AssemblyRequired::DAY, i + 1));
+            }
+        }
+    }
+
+    let instructions: HashMap<&str, Gate> = parsed.into_iter().collect();
+
+    if errors.is_empty() {
+        if let Some(wire) = find_loop(&instructions) {
+            let i = sinks[wire];
+            let column = columns(lines[i]).last().map_or(1, |(c, _)| c);
+            let e = ParseError::new(column, wire, "this wire depends on itself");
+            errors.push(e.at(AssemblyRequired::DAY, i + 1));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(instructions)
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+/// Find a wire whose signal depends on itself, which would otherwise send
+/// `get_signal` round in circles.
+fn find_loop<'a>(instructions: &HashMap<&'a str, Gate<'a>>) -> Option<&'a str> {
+    fn visit<'a>(wire: &'a str, insts: &HashMap<&'a str, Gate<'a>>,
+                 done: &mut HashSet<&'a str>, path: &mut HashSet<&'a str>) -> Option<&'a str> {
+
+        if done.contains(wire) {
+            return None;
+        }
+
+        if !path.insert(wire) {
+            return Some(wire);
+        }
+
+        if let Some(gate) = insts.get(wire) {
+            for input in gate.inputs() {
+                if let Some(w) = visit(input, insts, done, path) {
+                    return Some(w);
+                }
+            }
+        }
+
+        path.remove(wire);
+        done.insert(wire);
+        None
+    }
+
+    let mut done = HashSet::new();
+    let mut path = HashSet::new();
+    let mut wires: Vec<&str> = instructions.keys().copied().collect();
+    wires.sort_unstable();
+
+    wires.into_iter().find_map(|w| visit(w, instructions, &mut done, &mut path))
+}
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub enum Parameter<'a> {
+    Label(&'a str),
+    Value(u16),
+}
+
+#[derive(Clone, Debug)]
+#[derive(PartialEq)]
+pub enum Gate<'b> {
+    None(Parameter<'b>),
+    Not(&'b str),
+    And(Parameter<'b>, &'b str),
+    Or(&'b str, &'b str),
+    Lshift(&'b str, u8),
+    Rshift(&'b str, u8),
+}
+
+impl fmt::Display for Parameter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Parameter::Label(l) => write!(f, "{}", l),
+            Parameter::Value(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// A gate prints as the left-hand side of an instruction, without `-> wire`.
+impl fmt::Display for Gate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Gate::None(p) => write!(f, "{}", p),
+            Gate::Not(n) => write!(f, "NOT {}", n),
+            Gate::And(a1, a2) => write!(f, "{} AND {}", a1, a2),
+            Gate::Or(o1, o2) => write!(f, "{} OR {}", o1, o2),
+            Gate::Lshift(l, s) => write!(f, "{} LSHIFT {}", l, s),
+            Gate::Rshift(r, s) => write!(f, "{} RSHIFT {}", r, s),
+        }
+    }
+}
+
+impl<'b> Gate<'b> {
+    /// The wires that feed into this gate.
+    pub fn inputs(&self) -> Vec<&'b str> {
+        match *self {
+            Gate::None(Parameter::Label(l)) => vec![l],
+            Gate::None(Parameter::Value(_)) => vec![],
+            Gate::Not(n) => vec![n],
+            Gate::And(Parameter::Label(l), a) => vec![l, a],
+            Gate::And(Parameter::Value(_), a) => vec![a],
+            Gate::Or(o1, o2) => vec![o1, o2],
+            Gate::Lshift(l, _) | Gate::Rshift(l, _) => vec![l],
+        }
+    }
+}
+
+pub fn parse_line(line: &str) -> std::result::Result<(&str, Gate<'_>), ParseError> {
+    lazy_static! {
+        static ref INST: Regex = Regex::new(INST_REGEX).unwrap();
+    }
+
+    let tokens = match INST.captures(line) {
+        Ok(Some(t)) => t,
+        _ => return Err(ParseError::new(1, line, "invalid instruction")),
+    };
+
+    let lhs = tokens.get(1).expect("LHS missing.").as_str();
+    let sink = tokens.get(2).expect("Sink missing.").as_str();
+
+    Ok((sink, parse_gate(lhs)?))
+}
+
+pub fn parse_gate(gate: &str) -> std::result::Result<Gate<'_>, ParseError> {
+    let tokens: Vec<(usize, &str)> = columns(gate).collect();
+
+    match tokens.len() {
+        1 => {
+            match tokens[0].1.parse::<u16>() {
+                Ok(n) => Ok(Gate::None(Parameter::Value(n))),
+                Err(_) => Ok(Gate::None(Parameter::Label(label(tokens[0])?))),
+            }
+        },
+        2 => {
+            match tokens[0].1 {
+                "NOT" => Ok(Gate::Not(label(tokens[1])?)),
+                op => Err(ParseError::new(tokens[0].0, op, "unknown operator")),
+            }
+        },
+        3 => {
+            match tokens[1].1 {
+                "AND" => {
+                    if tokens[0].1.parse::<u16>().is_err() {
+                        label(tokens[0])?;
+                    }
+                    Ok(parse_and(tokens[0].1, label(tokens[2])?))
+                },
+                "OR" => Ok(Gate::Or(label(tokens[0])?, label(tokens[2])?)),
+                "LSHIFT" => Ok(Gate::Lshift(label(tokens[0])?, shift(tokens[2])?)),
+                "RSHIFT" => Ok(Gate::Rshift(label(tokens[0])?, shift(tokens[2])?)),
+                op => Err(ParseError::new(tokens[1].0, op, "unknown operator")),
+            }
+        },
+        _ => Err(ParseError::new(1, gate, "invalid gate")),
+    }
+}
+
+pub fn parse_and<'d>(arg1: &'d str, arg2: &'d str) -> Gate<'d> {
+    let arg = match arg1.parse::<u16>() {
+        Ok(n) => Parameter::Value(n),
+        Err(_) => Parameter::Label(arg1),
+    };
+
+    Gate::And(arg, arg2)
+}
+
+// Split on single spaces, keeping the column at which each token starts.
+fn columns(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split(' ').scan(1, |column, token| {
+        let start = *column;
+        *column += token.chars().count() + 1;
+        Some((start, token))
+    })
+}
+
+fn label((column, token): (usize, &str)) -> std::result::Result<&str, ParseError> {
+    if !token.is_empty() && token.chars().all(|c| c.is_ascii_lowercase()) {
+        Ok(token)
+    } else {
+        Err(ParseError::new(column, token, "invalid wire name"))
+    }
+}
+
+fn shift((column, token): (usize, &str)) -> std::result::Result<u8, ParseError> {
+    match token.parse::<u8>() {
+        Ok(s) if s < 16 => Ok(s),
+        _ => Err(ParseError::new(column, token, "invalid shift")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn and_gate() {
+        assert_eq!(
+            Gate::And(Parameter::Label("aa"), "bb"), parse_and("aa", "bb")
+        );
+        assert_eq!(
+            Gate::And(Parameter::Value(1), "cc"), parse_and("1", "cc")
+        );
+    }
+
+    #[test]
+    fn gates() {
+        assert_eq!(Ok(Gate::Or("aa", "bb")), parse_gate("aa OR bb"));
+        assert_eq!(
+            Ok(Gate::And(Parameter::Label("aa"), "bb")), parse_gate("aa AND bb")
+        );
+        assert_eq!(
+            Ok(Gate::And(Parameter::Value(0), "bb")), parse_gate("0 AND bb")
+        );
+        assert_eq!(Ok(Gate::Not("bb")), parse_gate("NOT bb"));
+        assert_eq!(
+            Ok(Gate::None(Parameter::Label("bb"))), parse_gate("bb")
+        );
+        assert_eq!(
+            Ok(Gate::None(Parameter::Value(12345))), parse_gate("12345")
+        );
+        assert_eq!(Ok(Gate::Lshift("aa", 15)), parse_gate("aa LSHIFT 15"));
+        assert_eq!(Ok(Gate::Rshift("aa", 3)), parse_gate("aa RSHIFT 3"));
+    }
+
+    #[test]
+    fn print_gates() {
+        for gate in &["aa OR bb", "1 AND bb", "NOT x", "x LSHIFT 2", "y RSHIFT 15", "123", "y"] {
+            assert_eq!(*gate, parse_gate(gate).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn bad_gates() {
+        assert_eq!(Err(ParseError::new(4, "XOR", "unknown operator")), parse_gate("aa XOR bb"));
+        assert_eq!(Err(ParseError::new(1, "NOR", "unknown operator")), parse_gate("NOR bb"));
+        assert_eq!(Err(ParseError::new(11, "16", "invalid shift")), parse_gate("aa LSHIFT 16"));
+        assert_eq!(Err(ParseError::new(1, "1", "invalid wire name")), parse_gate("1 OR bb"));
+        assert_eq!(Err(ParseError::new(1, "Bb", "invalid wire name")), parse_gate("Bb"));
+        assert_eq!(Err(ParseError::new(1, "a b c d", "invalid gate")), parse_gate("a b c d"));
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            Ok(("x", Gate::None(Parameter::Value(123)))), parse_line("123 -> x")
+        );
+        assert_eq!(
+            Ok(("d", Gate::And(Parameter::Label("x"), "y"))), parse_line("x AND y -> d")
+        );
+        assert_eq!(
+            Ok(("g", Gate::Rshift("y", 2))), parse_line("y RSHIFT 2 -> g")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, "x AND y => d", "invalid instruction")),
+            parse_line("x AND y => d")
+        );
+    }
+
+    #[test]
+    fn circuit() {
+        let circuit = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\n\
+                       x LSHIFT 2 -> f\ny RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i";
+        let instructions = parse_instructions(circuit).unwrap();
+
+        assert_eq!(72, get_signal("d", &instructions));
+        assert_eq!(507, get_signal("e", &instructions));
+        assert_eq!(492, get_signal("f", &instructions));
+        assert_eq!(114, get_signal("g", &instructions));
+        assert_eq!(65412, get_signal("h", &instructions));
+        assert_eq!(65079, get_signal("i", &instructions));
+    }
+
+    #[test]
+    fn bad_circuits() {
+        match parse_instructions("1 -> a\na OR zz -> b\nNOT yy -> c") {
+            Err(Error::Parse(errors)) => {
+                assert_eq!(ParseError::new(6, "zz", "no instruction drives this wire").at(7, 2), errors[0]);
+                assert_eq!((3, 5), (errors[1].line, errors[1].column));
+            },
+            _ => panic!("Expected parse errors."),
+        }
+
+        match parse_instructions("b -> a\nc AND a -> b\n5 -> c") {
+            Err(Error::Parse(errors)) => {
+                assert_eq!(1, errors.len());
+                assert_eq!("this wire depends on itself", errors[0].reason);
+            },
+            _ => panic!("Expected parse errors."),
+        }
+
+        assert!(AssemblyRequired::parse("1 -> b").is_err());
+    }
+}
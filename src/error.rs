@@ -0,0 +1,169 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::error;
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// Every problem found while parsing an input, in input order.
+    Parse(Vec<ParseError>),
+    /// The input parsed but cannot be solved, e.g. a required wire is missing.
+    Invalid { day: u8, reason: String },
+}
+
+impl Error {
+    pub fn invalid(day: u8, reason: impl Into<String>) -> Error {
+        Error::Invalid { day, reason: reason.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse(errors) => {
+                for (i, e) in errors.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", e)?;
+                }
+
+                Ok(())
+            },
+            Error::Invalid { day, reason } => write!(f, "day {}: {}", day, reason),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Parse(vec![e])
+    }
+}
+
+/// A problem with a single piece of an input. Lines and columns count from
+/// one, and columns are in characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// A parse error at the given column of the first line. Use `at` to
+    /// place it elsewhere.
+    pub fn new(column: usize, text: &str, reason: impl Into<String>) -> ParseError {
+        ParseError {
+            day: 0,
+            line: 1,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn at(self, day: u8, line: usize) -> ParseError {
+        ParseError { day, line, ..self }
+    }
+
+    /// Move the error along by `columns`, for when a piece of a line has
+    /// been parsed on its own.
+    pub fn offset(self, columns: usize) -> ParseError {
+        ParseError { column: self.column + columns, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "day {}, line {}, column {}: {}: {:?}",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parse each line of `input` with `parse`, collecting every error rather
+/// than stopping at the first.
+pub fn parse_lines<'a, T, F>(day: u8, input: &'a str, mut parse: F) -> Result<Vec<T>>
+where
+    F: FnMut(&'a str) -> std::result::Result<T, ParseError>,
+{
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        match parse(line) {
+            Ok(item) => items.push(item),
+            Err(e) => errors.push(e.at(day, i + 1)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(items)
+    } else {
+        Err(Error::Parse(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(line: &str) -> std::result::Result<u32, ParseError> {
+        line.parse().map_err(|_| ParseError::new(1, line, "not a number"))
+    }
+
+    #[test]
+    fn all_lines_parsed() {
+        assert_eq!(vec![1, 2, 3], parse_lines(1, "1\n2\n3", number).unwrap());
+    }
+
+    #[test]
+    fn all_errors_collected() {
+        match parse_lines(2, "1\nx\n3\ny", number) {
+            Err(Error::Parse(errors)) => {
+                assert_eq!(2, errors.len());
+                assert_eq!(ParseError::new(1, "x", "not a number").at(2, 2), errors[0]);
+                assert_eq!(4, errors[1].line);
+            },
+            _ => panic!("Expected parse errors."),
+        }
+    }
+
+    #[test]
+    fn display() {
+        let e = ParseError::new(3, "XOR", "unknown operator").at(7, 12).offset(2);
+        assert_eq!("day 7, line 12, column 5: unknown operator: \"XOR\"", e.to_string());
+    }
+}
@@ -0,0 +1,249 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+/// A small, seedable pseudo-random number generator (SplitMix64), so that
+/// generated inputs can be reproduced exactly from their seed.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot choose from an empty range.");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `lo..=hi`.
+    pub fn between(&mut self, lo: usize, hi: usize) -> usize {
+        lo + self.below(hi - lo + 1)
+    }
+
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    pub fn letters(&mut self, len: usize) -> String {
+        (0..len).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
+
+/// The default size of generated input for each day, roughly that of the
+/// real puzzle inputs.
+pub fn default_size(day: u8) -> usize {
+    match day {
+        1 => 7_000,
+        2 => 1_000,
+        3 => 8_192,
+        4 => 8,
+        5 => 1_000,
+        6 => 300,
+        _ => 340,
+    }
+}
+
+/// Generate a valid input for the given day. What `size` counts depends on
+/// the day: characters, lines, key length or wires.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut rng = Rng::new(seed);
+
+    let input = match day {
+        1 => instructions(&mut rng, size),
+        2 => parcels(&mut rng, size, 30),
+        3 => directions(&mut rng, size),
+        4 => rng.letters(size.max(1)),
+        5 => candidate_strings(&mut rng, size, 16),
+        6 => light_commands(&mut rng, size),
+        7 => circuit(&mut rng, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// Elevator instructions for day 1.
+pub fn instructions(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| if rng.chance(50) { '(' } else { ')' }).collect()
+}
+
+/// Parcel dimensions for day 2, one `LxWxH` per line.
+pub fn parcels(rng: &mut Rng, count: usize, max_side: usize) -> String {
+    let lines: Vec<String> = (0..count).map(|_| {
+        let mut side = || rng.between(1, max_side.max(1));
+        format!("{}x{}x{}", side(), side(), side())
+    }).collect();
+
+    lines.join("\n")
+}
+
+/// Directions for day 3.
+pub fn directions(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| *rng.choose(&['^', 'v', '<', '>'])).collect()
+}
+
+/// Strings for the elves to judge on day 5, one per line.
+pub fn candidate_strings(rng: &mut Rng, count: usize, len: usize) -> String {
+    let lines: Vec<String> = (0..count).map(|_| rng.letters(len)).collect();
+
+    lines.join("\n")
+}
+
+/// Light commands for day 6, one per line.
+pub fn light_commands(rng: &mut Rng, count: usize) -> String {
+    let lines: Vec<String> = (0..count).map(|_| {
+        let action = *rng.choose(&["turn on", "turn off", "toggle"]);
+        let (x1, x2) = (rng.below(1000), rng.below(1000));
+        let (y1, y2) = (rng.below(1000), rng.below(1000));
+
+        format!(
+            "{} {},{} through {},{}",
+            action, x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)
+        )
+    }).collect();
+
+    lines.join("\n")
+}
+
+/// An acyclic circuit for day 7 with `wires` wires, in a random order.
+/// Wire `b` is always driven by a plain signal and wire `a` always depends
+/// on other wires, as in the puzzle.
+pub fn circuit(rng: &mut Rng, wires: usize) -> String {
+    let mut names = vec!["b".to_string()];
+    let mut lines = vec![format!("{} -> b", rng.below(65_536))];
+
+    // Every wire is driven by wires defined before it, so there are no loops.
+    for i in 0..wires.saturating_sub(2) {
+        let name = wire_name(i + 2);
+        let gate = gate(rng, &names);
+
+        lines.push(format!("{} -> {}", gate, name));
+        names.push(name);
+    }
+
+    let last = names.last().expect("There is always a b wire.");
+    lines.push(format!("{} OR {} -> a", last, rng.choose(&names)));
+
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+fn gate(rng: &mut Rng, names: &[String]) -> String {
+    let mut wire = || rng.choose(names).clone();
+    let (x, y) = (wire(), wire());
+
+    match rng.below(8) {
+        0 => format!("{}", rng.below(65_536)),
+        1 => x,
+        2 => format!("NOT {}", x),
+        3 => format!("{} AND {}", x, y),
+        4 => format!("1 AND {}", x),
+        5 => format!("{} OR {}", x, y),
+        6 => format!("{} LSHIFT {}", x, rng.below(16)),
+        _ => format!("{} RSHIFT {}", x, rng.below(16)),
+    }
+}
+
+/// Wire names in the order a, b, ..., z, aa, ab, ...
+fn wire_name(mut i: usize) -> String {
+    let mut name = Vec::new();
+
+    loop {
+        name.push(b'a' + (i % 26) as u8);
+        if i < 26 {
+            break;
+        }
+        i = i / 26 - 1;
+    }
+
+    name.reverse();
+    String::from_utf8(name).expect("Wire names are ASCII.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{self, Part};
+
+    #[test]
+    fn reproducible() {
+        assert_eq!(generate(6, 42, 50), generate(6, 42, 50));
+        assert_ne!(generate(6, 42, 50), generate(6, 43, 50));
+        assert_eq!(None, generate(26, 42, 50));
+    }
+
+    #[test]
+    fn rng_ranges() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..1_000 {
+            assert!(rng.below(7) < 7);
+            assert!((3..=5).contains(&rng.between(3, 5)));
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("a", wire_name(0));
+        assert_eq!("z", wire_name(25));
+        assert_eq!("aa", wire_name(26));
+        assert_eq!("ba", wire_name(52));
+        assert_eq!("aaa", wire_name(702));
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(500, generate(1, 1, 500).unwrap().len());
+        assert_eq!(20, generate(2, 1, 20).unwrap().lines().count());
+        assert_eq!(30, generate(7, 1, 30).unwrap().lines().count());
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..5 {
+            for day in [1, 2, 3, 5, 6, 7] {
+                let input = generate(day, seed, 50).unwrap();
+                let solver = runner::solver(day).unwrap();
+
+                if let Err(e) = solver.solve(&input, Part::BOTH) {
+                    panic!("Day {} with seed {}: {}", day, seed, e);
+                }
+            }
+
+            // Solving day 4 means a long search, so just check it parses.
+            let key = generate(4, seed, 8).unwrap();
+            assert!(solver_parses(4, &key));
+        }
+    }
+
+    fn solver_parses(day: u8, input: &str) -> bool {
+        // An empty set of parts still parses the input.
+        runner::solver(day).unwrap().solve(input, &[]).is_ok()
+    }
+}
@@ -0,0 +1,101 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable used to choose the directory holding the
+/// default input files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "./etc";
+
+/// Where to read a day's puzzle input from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    /// `<name>.txt` in the input directory, see `input_dir`.
+    Default,
+    /// `<name>.txt` in the given directory.
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    /// Load the input for the puzzle with the given name.
+    pub fn load(&self, name: &str) -> io::Result<String> {
+        match self {
+            Source::Default => read_file(&default_path(&input_dir(), name)),
+            Source::Dir(dir) => read_file(&default_path(dir, name)),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            },
+            Source::Inline(input) => Ok(input.clone()),
+        }
+    }
+
+    /// Whether this source can provide different input for each puzzle.
+    pub fn is_per_puzzle(&self) -> bool {
+        matches!(self, Source::Default | Source::Dir(_))
+    }
+}
+
+/// The directory named by `AOC_INPUT_DIR`, or `./etc` if it is not set.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(DEFAULT_INPUT_DIR),
+    }
+}
+
+pub fn default_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{}.txt", name))
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|e| {
+        io::Error::new(e.kind(), format!("could not read {}: {}", path.display(), e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn etc() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("etc")
+    }
+
+    #[test]
+    fn inline() {
+        let source = Source::Inline("^>v<".to_string());
+        assert_eq!("^>v<", source.load("spherical_houses").unwrap());
+        assert!(!source.is_per_puzzle());
+    }
+
+    #[test]
+    fn from_dir_and_file() {
+        let key = Source::Dir(etc()).load("stocking_stuffer").unwrap();
+        assert_eq!("yzbqklnj", key.trim());
+
+        let path = default_path(&etc(), "stocking_stuffer");
+        assert_eq!(key, Source::File(path).load("anything").unwrap());
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = Source::Dir(etc()).load("no_such_day").unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, err.kind());
+        assert!(err.to_string().contains("no_such_day.txt"));
+    }
+}
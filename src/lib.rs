@@ -5,3 +5,27 @@
 //
 // Public Domain
 //
+
+#[macro_use]
+extern crate lazy_static;
+
+pub mod error;
+pub mod solution;
+pub mod input;
+pub mod bench;
+pub mod answers;
+pub mod output;
+pub mod generate;
+pub mod pool;
+pub mod runner;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+
+pub use error::{Error, ParseError, Result};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,158 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+use crate::runner::Part;
+use crate::Answer;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line.
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{}'", s)),
+        }
+    }
+}
+
+/// Everything known about one answer, for output.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: Part,
+    pub answer: &'a Answer,
+    pub input_hash: &'a str,
+    pub elapsed: Duration,
+}
+
+const CSV_HEADER: &str = "day,part,answer,answer_type,input_hash,elapsed_ns";
+
+impl Format {
+    /// The line to print before any records, if this format has one.
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some(CSV_HEADER),
+            _ => None,
+        }
+    }
+
+    pub fn record(&self, r: &Record) -> String {
+        match self {
+            Format::Text => format!("Day {}, Part {}: {}", r.day, r.part, r.answer),
+            Format::Json => {
+                let answer = match r.answer {
+                    Answer::Text(s) => json_string(s),
+                    Answer::None => "null".to_string(),
+                    a => a.to_string(),
+                };
+
+                format!(
+                    "{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\
+                     \"input_hash\":{},\"elapsed_ns\":{}}}",
+                    r.day, r.part, answer, json_string(r.answer.kind()),
+                    json_string(r.input_hash), r.elapsed.as_nanos()
+                )
+            },
+            Format::Csv => format!(
+                "{},{},{},{},{},{}",
+                r.day, r.part, csv_field(&r.answer.to_string()), csv_field(r.answer.kind()),
+                csv_field(r.input_hash), r.elapsed.as_nanos()
+            ),
+        }
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: &Answer) -> Record<'_> {
+        Record {
+            day: 7,
+            part: Part::Two,
+            answer,
+            input_hash: "abc123",
+            elapsed: Duration::from_micros(15),
+        }
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Ok(Format::Json), "json".parse());
+        assert!("xml".parse::<Format>().is_err());
+        assert_eq!(Some(CSV_HEADER), Format::Csv.header());
+        assert_eq!(None, Format::Json.header());
+    }
+
+    #[test]
+    fn records() {
+        let answer = Answer::Unsigned(2797);
+        let r = record(&answer);
+
+        assert_eq!("Day 7, Part 2: 2797", Format::Text.record(&r));
+        assert_eq!(
+            "{\"day\":7,\"part\":2,\"answer\":2797,\"answer_type\":\"unsigned\",\
+             \"input_hash\":\"abc123\",\"elapsed_ns\":15000}",
+            Format::Json.record(&r)
+        );
+        assert_eq!("7,2,2797,unsigned,abc123,15000", Format::Csv.record(&r));
+
+        let none = Answer::None;
+        assert!(Format::Json.record(&record(&none)).contains("\"answer\":null,"));
+    }
+
+    #[test]
+    fn text_answers_escaped() {
+        let answer = Answer::Text("say \"hi\", then\tgo".to_string());
+        let r = record(&answer);
+
+        assert!(Format::Json.record(&r).contains("\"answer\":\"say \\\"hi\\\", then\\tgo\""));
+        assert!(Format::Csv.record(&r).starts_with("7,2,\"say \"\"hi\"\", then\tgo\",text"));
+    }
+}
@@ -0,0 +1,72 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// The number of threads to use when none is given: one per available CPU.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map(NonZeroUsize::get).unwrap_or(1)
+}
+
+/// Call `f` on every task using up to `jobs` threads. The results come
+/// back in the same order as the tasks, however long each one takes.
+pub fn parallel_map<T, R, F>(tasks: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(tasks.len()));
+
+    // Each thread takes the next unclaimed task until there are none left.
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let task = match tasks.get(i) {
+                    Some(t) => t,
+                    None => break,
+                };
+
+                let result = f(task);
+                results.lock().expect("No task panics while holding the lock.").push((i, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().expect("Every thread has finished.");
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_in_task_order() {
+        let tasks: Vec<u64> = (0..20).collect();
+
+        // Make the early tasks the slowest, so they finish last.
+        let slow_first = |&n: &u64| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        };
+
+        let expected: Vec<u64> = tasks.iter().map(|n| n * n).collect();
+        assert_eq!(expected, parallel_map(&tasks, 4, slow_first));
+        assert_eq!(expected, parallel_map(&tasks, 1, slow_first));
+        assert_eq!(expected, parallel_map(&tasks, 100, slow_first));
+        assert!(parallel_map(&[] as &[u64], 4, slow_first).is_empty());
+    }
+}
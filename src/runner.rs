@@ -0,0 +1,190 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::fmt;
+use std::hint;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+use crate::bench::Phase;
+use crate::{Answer, Result, Solution};
+use crate::day01::NotQuiteLisp;
+use crate::day02::NoMath;
+use crate::day03::SphericalHouses;
+use crate::day04::StockingStuffer;
+use crate::day05::InternElves;
+use crate::day06::FireHazard;
+use crate::day07::AssemblyRequired;
+
+/// Every solver in the crate, in day order.
+pub static SOLVERS: &[&dyn Solver] = &[
+    &Day::<NotQuiteLisp>::new(),
+    &Day::<NoMath>::new(),
+    &Day::<SphericalHouses>::new(),
+    &Day::<StockingStuffer>::new(),
+    &Day::<InternElves>::new(),
+    &Day::<FireHazard>::new(),
+    &Day::<AssemblyRequired>::new(),
+];
+
+/// Look up a solver in the registry by its day number.
+pub fn solver(day: u8) -> Option<&'static dyn Solver> {
+    SOLVERS.iter().find(|s| s.day() == day).copied()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const BOTH: &'static [Part] = &[Part::One, Part::Two];
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+/// The answer to one part of a puzzle, and how long it took to find.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// A type-erased view of a `Solution`, so that days with different input
+/// and answer types can be driven from the same registry.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn name(&self) -> &'static str;
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Solved>>;
+
+    /// Time parsing and then each of `parts`, `runs` times each.
+    fn time(&self, input: &str, parts: &[Part], runs: usize)
+        -> Result<Vec<(Phase, Vec<Duration>)>>;
+}
+
+pub struct Day<S>(PhantomData<fn() -> S>);
+
+impl<S> Day<S> {
+    pub const fn new() -> Day<S> {
+        Day(PhantomData)
+    }
+}
+
+impl<S> Default for Day<S> {
+    fn default() -> Day<S> {
+        Day::new()
+    }
+}
+
+impl<S: Solution> Solver for Day<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Solved>> {
+        let input = S::parse(input.trim_end())?;
+
+        Ok(parts.iter().map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input).into(),
+                Part::Two => S::part2(&input).into(),
+            };
+
+            Solved { part, answer, elapsed: start.elapsed() }
+        }).collect())
+    }
+
+    fn time(&self, input: &str, parts: &[Part], runs: usize)
+        -> Result<Vec<(Phase, Vec<Duration>)>> {
+
+        let input = input.trim_end();
+        let mut samples = Vec::with_capacity(runs);
+        let mut parsed = S::parse(input)?;
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            parsed = S::parse(hint::black_box(input))?;
+            samples.push(start.elapsed());
+        }
+
+        let mut results = vec![(Phase::Parse, samples)];
+
+        for &part in parts {
+            let samples = (0..runs).map(|_| {
+                let start = Instant::now();
+                match part {
+                    Part::One => { hint::black_box(S::part1(&parsed)); },
+                    Part::Two => { hint::black_box(S::part2(&parsed)); },
+                }
+                start.elapsed()
+            }).collect();
+
+            results.push((Phase::Solve(part), samples));
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_in_day_order() {
+        let days: Vec<u8> = SOLVERS.iter().map(|s| s.day()).collect();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], days);
+    }
+
+    #[test]
+    fn find_solver() {
+        assert_eq!("fire_hazard", solver(6).unwrap().name());
+        assert!(solver(25).is_none());
+    }
+
+    #[test]
+    fn solve_parts() {
+        let day1 = solver(1).unwrap();
+
+        let solved = day1.solve("())\n", Part::BOTH).unwrap();
+        assert_eq!((Part::One, Answer::Signed(-1)), (solved[0].part, solved[0].answer.clone()));
+        assert_eq!((Part::Two, Answer::Unsigned(3)), (solved[1].part, solved[1].answer.clone()));
+
+        let solved = day1.solve(")", &[Part::Two]).unwrap();
+        assert_eq!(1, solved.len());
+        assert_eq!((Part::Two, Answer::Unsigned(1)), (solved[0].part, solved[0].answer.clone()));
+    }
+
+    #[test]
+    fn parts_answer_separately() {
+        // Only the ribbon for these parcels is too much to count.
+        let input = "1048576x1048576x1048576\n".repeat(16);
+        let solved = solver(2).unwrap().solve(&input, Part::BOTH).unwrap();
+
+        assert_eq!(Answer::Unsigned(7 << 44), solved[0].answer);
+        assert_eq!(Answer::None, solved[1].answer);
+    }
+}
@@ -0,0 +1,108 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+use std::fmt;
+use crate::Result;
+
+/// A single day's puzzle: how to parse its input and how to solve each part.
+pub trait Solution {
+    const DAY: u8;
+    const NAME: &'static str;
+
+    type Input<'a>;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+/// The answer to one part of a puzzle, independent of the type the solver
+/// used to compute it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    /// The puzzle has no answer for this input, e.g. a floor is never reached.
+    None,
+}
+
+impl Answer {
+    /// A stable name for the kind of answer, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "signed",
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Text(_) => "text",
+            Answer::None => "none",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => write!(f, "none"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::$variant(n as $wide)
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(o: Option<T>) -> Answer {
+        o.map_or(Answer::None, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        assert_eq!(Answer::Signed(-1), Answer::from(-1_i32));
+        assert_eq!(Answer::Unsigned(16076), Answer::from(16076_u16));
+        assert_eq!("-1", Answer::from(-1_i32).to_string());
+        assert_eq!("abc", Answer::from("abc".to_string()).to_string());
+        assert_eq!(Answer::Unsigned(5), Answer::from(Some(5_usize)));
+        assert_eq!("none", Answer::from(None::<usize>).to_string());
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!("signed", Answer::from(-1_i32).kind());
+        assert_eq!("unsigned", Answer::from(Some(5_usize)).kind());
+        assert_eq!("text", Answer::from("abc".to_string()).kind());
+        assert_eq!("none", Answer::from(None::<usize>).kind());
+    }
+}
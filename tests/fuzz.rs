@@ -0,0 +1,87 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+// Fuzz tests: feed every day's parser random bytes, and valid inputs with
+// random damage, and check that nothing panics. Anything that parses is
+// solved too, apart from day 4 whose search could take a very long time.
+
+use std::panic::{self, AssertUnwindSafe};
+use aoc_2015::generate::{self, Rng};
+use aoc_2015::runner::{Part, SOLVERS};
+
+const CASES: u64 = 200;
+
+// Bytes that appear in valid inputs, so that mutations are more likely to
+// produce something that nearly parses.
+const INTERESTING: &[u8] = b"()^v<>x0123456789 ,\n-abcdefghijklmnopqrstuvwxyz\
+                             ANDORNOTLSHIFTRSHIFTturnofftogglethrough";
+
+fn random_bytes(rng: &mut Rng, len: usize) -> Vec<u8> {
+    (0..len).map(|_| rng.below(256) as u8).collect()
+}
+
+fn mutate(rng: &mut Rng, input: &str) -> Vec<u8> {
+    let mut bytes = input.as_bytes().to_vec();
+
+    for _ in 0..rng.between(1, 4) {
+        let byte = if rng.chance(80) { *rng.choose(INTERESTING) } else { rng.below(256) as u8 };
+        let i = rng.below(bytes.len() + 1);
+
+        match rng.below(3) {
+            0 if i < bytes.len() => bytes[i] = byte,
+            1 if i < bytes.len() => { bytes.remove(i); },
+            _ => bytes.insert(i, byte),
+        }
+    }
+
+    bytes
+}
+
+fn survives(day: u8, seed: u64, bytes: &[u8]) {
+    let input = String::from_utf8_lossy(bytes);
+    let solver = SOLVERS.iter().find(|s| s.day() == day).unwrap();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        if solver.solve(&input, &[]).is_ok() && day != 4 {
+            solver.solve(&input, Part::BOTH).unwrap();
+        }
+    }));
+
+    if result.is_err() {
+        panic!("Day {} panicked with seed {} on input {:?}", day, seed, input);
+    }
+}
+
+#[test]
+fn random_bytes_never_panic() {
+    for solver in SOLVERS {
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let len = rng.below(200);
+
+            survives(solver.day(), seed, &random_bytes(&mut rng, len));
+        }
+    }
+}
+
+#[test]
+fn damaged_inputs_never_panic() {
+    for solver in SOLVERS {
+        let day = solver.day();
+
+        // Solving day 6 lights a full grid each time, so try fewer cases.
+        let cases = if day == 6 { CASES / 10 } else { CASES };
+
+        for seed in 0..cases {
+            let mut rng = Rng::new(seed);
+            let input = generate::generate(day, seed, 20).unwrap();
+
+            survives(day, seed, &mutate(&mut rng, &input));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//
+// Advent of Code 2015
+//
+// Robert Haines
+//
+// Public Domain
+//
+
+// Property tests: each property is checked against many seeded, randomly
+// generated inputs. A failure names the seed, so it can be reproduced.
+
+use aoc_2015::day01;
+use aoc_2015::day02::{self, Parcel};
+use aoc_2015::day03;
+use aoc_2015::day05;
+use aoc_2015::day06::{self, Action, Command, LightingGrid};
+use aoc_2015::day07::{self, AssemblyRequired};
+use aoc_2015::generate::{self, Rng};
+use aoc_2015::Solution;
+
+const CASES: u64 = 100;
+
+fn for_seeds<F: FnMut(u64, &mut Rng)>(cases: u64, mut property: F) {
+    for seed in 0..cases {
+        property(seed, &mut Rng::new(seed));
+    }
+}
+
+#[test]
+fn parcels_round_trip() {
+    for_seeds(CASES, |seed, rng| {
+        let (l, w, h) = (rng.between(1, 1000), rng.between(1, 1000), rng.between(1, 1000));
+        let parcel = Parcel::new(l as u32, w as u32, h as u32);
+
+        assert_eq!(Ok(parcel.clone()), Parcel::from_string(&parcel.to_string()), "seed {}", seed);
+
+        for line in generate::parcels(rng, 10, 30).lines() {
+            assert_eq!(line, Parcel::from_string(line).unwrap().to_string(), "seed {}", seed);
+        }
+    });
+}
+
+#[test]
+fn parcels_need_at_least_their_surface() {
+    for_seeds(CASES, |seed, rng| {
+        let parcels = day02::parse_input(&generate::parcels(rng, 20, 30)).unwrap();
+
+        for p in &parcels {
+            assert!(p.wrap_required() > p.area(), "seed {}: {}", seed, p);
+            assert!(p.ribbon_required() > p.volume(), "seed {}: {}", seed, p);
+        }
+    });
+}
+
+#[test]
+fn huge_parcels_are_counted_or_refused() {
+    for_seeds(CASES, |seed, rng| {
+        let parcels: Vec<Parcel> = (0..rng.between(1, 5)).map(|_| {
+            let mut side = || rng.next_u64() as u32;
+            Parcel::new(side(), side(), side())
+        }).collect();
+
+        // Either the total is exact, or it is refused for being too big.
+        let exact: u128 = parcels.iter().map(|p| p.wrap_required()).sum();
+        match day02::part1(&parcels) {
+            Ok(total) => assert_eq!(exact, total as u128, "seed {}", seed),
+            Err(_) => assert!(exact > u64::MAX as u128, "seed {}", seed),
+        }
+    });
+}
+
+#[test]
+fn light_commands_round_trip() {
+    let actions = [Action::TurnOn, Action::TurnOff, Action::Toggle];
+
+    for_seeds(CASES, |seed, rng| {
+        let command = Command {
+            action: *rng.choose(&actions),
+            lx: rng.below(1000),
+            ly: rng.below(1000),
+            hx: rng.below(1000),
+            hy: rng.below(1000),
+        };
+        let printed = command.to_string();
+
+        assert_eq!(Ok(command), LightingGrid::parse_command(&printed), "seed {}", seed);
+
+        for line in generate::light_commands(rng, 10).lines() {
+            let parsed = LightingGrid::parse_command(line).unwrap();
+            assert_eq!(line, parsed.to_string(), "seed {}", seed);
+        }
+    });
+}
+
+#[test]
+fn brightness_is_bounded() {
+    // Each case lights a full grid, so use fewer of them.
+    for_seeds(CASES / 10, |seed, rng| {
+        let commands = day06::FireHazard::parse(&generate::light_commands(rng, 20)).unwrap();
+        let area: u64 = commands.iter().map(|c| c.area()).sum();
+
+        let lit = day06::run(1, &commands);
+        assert!(lit <= area.min(1_000_000), "seed {}", seed);
+
+        let brightness = day06::run(2, &commands);
+        assert!(brightness <= 2 * area, "seed {}", seed);
+        assert!(brightness <= 2 * commands.len() as u64 * 1_000_000, "seed {}", seed);
+    });
+}
+
+#[test]
+fn gates_round_trip() {
+    for_seeds(CASES, |seed, rng| {
+        for line in generate::circuit(rng, 30).lines() {
+            let (sink, gate) = day07::parse_line(line).unwrap();
+            assert_eq!(line, format!("{} -> {}", gate, sink), "seed {}", seed);
+        }
+    });
+}
+
+#[test]
+fn signals_ignore_instruction_order() {
+    for_seeds(CASES, |seed, rng| {
+        let circuit = generate::circuit(rng, 50);
+        let mut lines: Vec<&str> = circuit.lines().collect();
+        rng.shuffle(&mut lines);
+        let shuffled = lines.join("\n");
+
+        let first = AssemblyRequired::parse(&circuit).unwrap();
+        let second = AssemblyRequired::parse(&shuffled).unwrap();
+
+        assert_eq!(
+            AssemblyRequired::part1(&first), AssemblyRequired::part1(&second), "seed {}", seed
+        );
+        assert_eq!(
+            AssemblyRequired::part2(&first), AssemblyRequired::part2(&second), "seed {}", seed
+        );
+    });
+}
+
+#[test]
+fn floors_are_bounded() {
+    for_seeds(CASES, |seed, rng| {
+        let len = rng.between(1, 500);
+        let instructions = generate::instructions(rng, len);
+        let floor = day01::part1(&instructions);
+        let basement = day01::part2(&instructions);
+
+        assert!(floor.unsigned_abs() as usize <= len, "seed {}", seed);
+        assert_eq!(len % 2, floor.unsigned_abs() as usize % 2, "seed {}", seed);
+
+        // The basement is an odd number of moves from the ground floor.
+        if let Some(pos) = basement {
+            assert!(pos % 2 == 1 && pos <= len, "seed {}", seed);
+        }
+
+        assert_eq!(basement.is_some(), day01::lowest_floor(&instructions) < 0, "seed {}", seed);
+    });
+}
+
+#[test]
+fn mirrored_routes_visit_as_many_houses() {
+    for_seeds(CASES, |seed, rng| {
+        let len = rng.between(0, 500);
+        let route = generate::directions(rng, len);
+        let mirror: String = route.chars().map(|c| match c {
+            '^' => 'v',
+            'v' => '^',
+            '<' => '>',
+            _ => '<',
+        }).collect();
+
+        let houses = day03::part1(&route).unwrap();
+        assert!(houses >= 1 && houses <= len + 1, "seed {}", seed);
+        assert_eq!(houses, day03::part1(&mirror).unwrap(), "seed {}", seed);
+        assert!(day03::part2(&route).unwrap() <= len + 1, "seed {}", seed);
+    });
+}
+
+#[test]
+fn nice_strings_are_counted_once() {
+    for_seeds(CASES, |seed, rng| {
+        let count = rng.between(0, 50);
+        let strings = generate::candidate_strings(rng, count, 16);
+
+        assert!(day05::part1(&strings) as usize <= count, "seed {}", seed);
+        assert!(day05::part2(&strings) as usize <= count, "seed {}", seed);
+    });
+}